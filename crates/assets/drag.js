@@ -6,6 +6,11 @@ function detectOS() {
   return 'unknown';
 }
 
+// Gleiches Format wie PyFrame.call: [callbackId, method, args]
+function postCommand(command, args) {
+  window.ipc.postMessage(JSON.stringify([0, `frame.${command}`, args || []]));
+}
+
 function invoke_frameless(id) {
   if (!window.ipc || typeof window.ipc.postMessage !== 'function') {
     console.warn('IPC nicht verfügbar – keine Aktionen ausgeführt.');
@@ -41,11 +46,11 @@ function invoke_frameless(id) {
       event.preventDefault();
       event.stopImmediatePropagation();
 
-      const command = event.detail === 2 ? 'maximize' : 'drag_window';
-      window.ipc.postMessage(command);
+      const command = event.detail === 2 ? 'maximize' : 'dragWindow';
+      postCommand(command);
     } else {
       // Wenn nicht Drag-Region → Koordinaten senden
-      window.ipc.postMessage(`mousedown:${event.clientX},${event.clientY}`);
+      postCommand('mouseDown', [event.clientX, event.clientY]);
     }
   }
 
@@ -57,14 +62,14 @@ function invoke_frameless(id) {
       event.clientX === initialMouseX &&
       event.clientY === initialMouseY
     ) {
-      window.ipc.postMessage('drag_window');
+      postCommand('dragWindow');
     }
   }
 
   // ========== NEU: Die „normalen“ Events ==========
   // Bewegung der Maus – nur Positionsdaten
   document.addEventListener('mousemove', (e) => {
-    window.ipc.postMessage(`mousemove:${e.clientX},${e.clientY}`);
+    postCommand('mouseMove', [e.clientX, e.clientY]);
   });
 
   // Mousedown: unser spezieller Handler
@@ -73,7 +78,7 @@ function invoke_frameless(id) {
   // Touchstart – Drag nur für Touch
   document.addEventListener('touchstart', (e) => {
     if (e.target.hasAttribute(DRAG_REGION_ATTR)) {
-      window.ipc.postMessage('drag_window');
+      postCommand('dragWindow');
     }
   });
 
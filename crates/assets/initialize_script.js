@@ -11,33 +11,47 @@
     return 'unknown';
   }
 
-  // Frameless Drag & Events
-  function initFrameless(id) {
-    if (!window.ipc || typeof window.ipc.postMessage !== 'function') {
-      console.warn('IPC nicht verfügbar  keine Aktionen ausgeführt.');
-      return;
-    }
+  // Frameless-Kit: Drag-Regionen, Resize-Ränder und Titelleisten-Buttons per Attribut
+  const DRAG_REGION_SELECTOR = '[data-pyframe-drag-region], [data-pyframe-darg-region]';
+  const NO_DRAG_SELECTOR = '[data-pyframe-no-drag], [data-pyframe-titlebar-button]';
+  const TITLEBAR_BUTTON_ATTR = 'data-pyframe-titlebar-button';
+  const TITLEBAR_COMMANDS = {
+    minimize: 'minimize',
+    maximize: 'maximize',
+    close: 'closeRequested',
+  };
 
-    const osName = detectOS();
-    const DRAG_REGION_ATTR = 'data-pyframe-darg-region';
-    const element = document.getElementById(id);
+  // Das Init-Skript kann mehrfach injiziert werden, der Zustand liegt deshalb global
+  var framelessState = window.__pyframeFrameless || (window.__pyframeFrameless = {
+    installed: false,
+    resize: false,
+  });
 
-    if (!element) {
-      console.warn(`Element mit ID "${id}" nicht gefunden.`);
-      return;
+  // Fenster-Befehle nutzen dasselbe Format wie PyFrame.call: [callbackId, method, args]
+  function postCommand(command, args) {
+    if (window.ipc && typeof window.ipc.postMessage === 'function') {
+      window.ipc.postMessage(JSON.stringify([0, `frame.${command}`, args || []]));
     }
+  }
 
-    element.setAttribute(DRAG_REGION_ATTR, '');
+  function isDragRegion(target) {
+    if (!(target instanceof Element)) return false;
+    if (target.closest(NO_DRAG_SELECTOR)) return false;
+    return target.closest(DRAG_REGION_SELECTOR) !== null;
+  }
+
+  function installFrameless() {
+    if (framelessState.installed) return;
+    framelessState.installed = true;
 
+    const osName = detectOS();
     let initialMouseX = 0, initialMouseY = 0;
 
-    // Mousedown-Handler
-    function handleMouseDown(event) {
-      if (
-        event.target.hasAttribute(DRAG_REGION_ATTR) &&
-        event.button === 0 &&
-        (event.detail === 1 || event.detail === 2)
-      ) {
+    document.addEventListener('mousedown', (event) => {
+      if (event.button !== 0) return;
+
+      if (isDragRegion(event.target) && (event.detail === 1 || event.detail === 2)) {
+        // macOS: Doppelklick erst beim mouseup auswerten
         if (osName === 'macos' && event.detail === 2) {
           initialMouseX = event.clientX;
           initialMouseY = event.clientY;
@@ -46,70 +60,123 @@
 
         event.preventDefault();
         event.stopImmediatePropagation();
-
-        const command = event.detail === 2 ? 'maximize' : 'drag_window';
-        window.ipc.postMessage(command);
-      } else {
-        window.ipc.postMessage(`mousedown:${event.clientX},${event.clientY}`);
+        postCommand(event.detail === 2 ? 'maximize' : 'dragWindow');
+      } else if (framelessState.resize) {
+        postCommand('mouseDown', [event.clientX, event.clientY]);
       }
-    }
+    });
 
-    // Mouseup für macOS (Double-Click prüfen)
-    function handleMouseUp(event) {
-      if (
-        event.target.hasAttribute(DRAG_REGION_ATTR) &&
-        event.button === 0 &&
-        event.detail === 2 &&
-        event.clientX === initialMouseX &&
-        event.clientY === initialMouseY
-      ) {
-        window.ipc.postMessage('drag_window');
-      }
+    if (osName === 'macos') {
+      document.addEventListener('mouseup', (event) => {
+        if (
+          isDragRegion(event.target) &&
+          event.button === 0 &&
+          event.detail === 2 &&
+          event.clientX === initialMouseX &&
+          event.clientY === initialMouseY
+        ) {
+          postCommand('maximize');
+        }
+      });
     }
 
-    // Event-Listener für Bewegungen und Touch
-    document.addEventListener('mousemove', (e) => {
-      window.ipc.postMessage(`mousemove:${e.clientX},${e.clientY}`);
+    document.addEventListener('mousemove', (event) => {
+      if (framelessState.resize) {
+        postCommand('mouseMove', [event.clientX, event.clientY]);
+      }
     });
 
-    document.addEventListener('mousedown', handleMouseDown);
+    document.addEventListener('touchstart', (event) => {
+      if (isDragRegion(event.target)) {
+        postCommand('dragWindow');
+      }
+    });
 
-    document.addEventListener('touchstart', (e) => {
-      if (e.target.hasAttribute(DRAG_REGION_ATTR)) {
-        window.ipc.postMessage('drag_window');
+    document.addEventListener('click', (event) => {
+      const button = event.target instanceof Element && event.target.closest(`[${TITLEBAR_BUTTON_ATTR}]`);
+      if (!button) return;
+      const command = TITLEBAR_COMMANDS[button.getAttribute(TITLEBAR_BUTTON_ATTR)];
+      if (command) {
+        event.preventDefault();
+        postCommand(command);
       }
     });
+  }
 
-    if (osName === 'macos') {
-      document.addEventListener('mouseup', handleMouseUp);
+  // Älterer Weg: Element per ID zur Drag-Region machen und Resize aktivieren
+  function initFrameless(id) {
+    const element = document.getElementById(id);
+    if (!element) {
+      console.warn(`Element mit ID "${id}" nicht gefunden.`);
+      return;
     }
+    element.setAttribute('data-pyframe-drag-region', '');
+    framelessState.resize = true;
   }
 
+  // Rahmenlose Fenster bekommen ihre Einstellungen vom Runtime (window_resize_inset)
+  if (window.__PYFRAME_FRAMELESS__) {
+    framelessState.resize = window.__PYFRAME_FRAMELESS__.resizeInset > 0;
+  }
+  installFrameless();
+
   // === PyFrame-Objekt & Event-System ===
   var PyFrame = {};
 
   var eventListeners = {};
 
+  // Hochfrequente Fenster-Events (moved, resized, ...) sendet Rust nur nach window.subscribe
+  function isWindowEvent(event) {
+    return event === '*' || event.startsWith('window.');
+  }
+
+  PyFrame.subscribe = function (events) {
+    return PyFrame.call('window.subscribe', [events]);
+  };
+
+  PyFrame.unsubscribe = function (events) {
+    return PyFrame.call('window.unsubscribe', [events]);
+  };
+
   PyFrame.addEventListener = function (event, listener) {
     if (!eventListeners[event]) eventListeners[event] = [];
+    if (eventListeners[event].length === 0 && isWindowEvent(event)) {
+      PyFrame.subscribe([event]).catch(() => {});
+    }
     eventListeners[event].push(listener);
   };
 
   PyFrame.removeEventListener = function (event, listener) {
     if (!eventListeners[event]) return;
     eventListeners[event] = eventListeners[event].filter(l => l !== listener);
+    if (eventListeners[event].length === 0 && isWindowEvent(event)) {
+      PyFrame.unsubscribe([event]).catch(() => {});
+    }
   };
 
   PyFrame.removeAllEventListeners = function (event) {
     if (!eventListeners[event]) return;
     eventListeners[event] = [];
+    if (isWindowEvent(event)) {
+      PyFrame.unsubscribe([event]).catch(() => {});
+    }
   };
 
   PyFrame.__emit__ = function (event, data) {
     setTimeout(() => {
       const keys = [event, event.split('.')[0] + '.*', '*'];
+      // app.beforeQuit: Listener brechen per preventDefault() oder Rückgabe false ab, Rust wartet auf die Antwort
+      const quitId = event === 'app.beforeQuit' && data ? data.quitId : null;
+      let prevented = false;
+      if (quitId != null) data.preventDefault = () => { prevented = true; };
+      const results = [];
       keys.forEach(key => {
-        (eventListeners[key] || []).forEach(listener => listener(event, data));
+        (eventListeners[key] || []).forEach(listener => results.push(listener(event, data)));
+      });
+      if (quitId == null) return;
+      Promise.allSettled(results).then(settled => {
+        const prevent = prevented || settled.some(r => r.status === 'fulfilled' && r.value === false);
+        PyFrame.call('app.beforeQuitReply', [quitId, prevent]).catch(() => {});
       });
     }, 0);
   };
@@ -159,14 +226,38 @@
     console.log('Proxy not supported, please use PyFrame.call instead');
   }
 
+  // Signalisiert, dass die Seite bereit ist (für window_show_when = "ready")
+  PyFrame.ready = function () {
+    postCommand('ready');
+  };
+
+  // Hot Reload (debug_resource): geänderte Stylesheets ohne Neuladen austauschen
+  PyFrame.addEventListener('resources.cssChanged', (event, paths) => {
+    let swapped = false;
+    document.querySelectorAll('link[rel="stylesheet"]').forEach(link => {
+      const url = new URL(link.href, location.href);
+      if (paths.includes(url.pathname.replace(/^\//, ''))) {
+        url.searchParams.set('pyframeReload', Date.now());
+        link.href = url.toString();
+        swapped = true;
+      }
+    });
+    // Per @import oder JS geladenes CSS lässt sich nicht gezielt tauschen
+    if (!swapped) location.reload();
+  });
+
+  // CSP-Nonce der Laufzeit, für dynamisch erzeugte <script>/<style>-Elemente
+  Object.defineProperty(PyFrame, 'nonce', {
+    get: () => window.__PYFRAME_NONCE__ || null,
+  });
+
   // IPC-Callback-Handler
   PyFrame.addEventListener('ipc.callback', (event, response) => {
     PyFrame.__resolve__(response);
   });
 
-  // Fenster-APIs entfernen
+  // Fenster-APIs entfernen (window.open bleibt, die New-Window-Policy entscheidet)
   delete window.close;
-  delete window.open;
 
   // Frameless-API als Teil von PyFrame verfügbar machen
   PyFrame.initFrameless = initFrameless;
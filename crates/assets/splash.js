@@ -0,0 +1,12 @@
+(function () {
+  // Fortschrittsanzeige des Splash-Fensters
+  window.PyFrameSplash = {
+    setProgress: function (message) {
+      var element = document.getElementById('pyframe-splash-progress');
+      if (element) {
+        element.textContent = message;
+      }
+      document.dispatchEvent(new CustomEvent('pyframe-splash-progress', { detail: message }));
+    }
+  };
+})();
@@ -0,0 +1,58 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use anyhow::{anyhow, Result};
+use pyframe_macros::pyframe_api;
+
+use crate::{api_manager::ApiManager, utils::UserEvent};
+
+pub fn register_api_instances(_api_manager: &mut ApiManager) {
+    _api_manager.register_api("app.quit", quit);
+    _api_manager.register_api("app.exit", exit);
+    _api_manager.register_api("app.blockQuit", block_quit);
+    _api_manager.register_api("app.beforeQuitReply", before_quit_reply);
+    _api_manager.register_api("app.shutdownComplete", shutdown_complete);
+    _api_manager.register_api("app.showMainWindow", show_main_window);
+}
+
+#[pyframe_api]
+fn quit(exit_code: Option<i32>) -> Result<()> {
+    app.proxy
+        .send_event(UserEvent::RequestQuit(exit_code.unwrap_or(0)))
+        .map_err(|_| anyhow!("Failed to send event"))
+}
+
+#[pyframe_api]
+fn exit(exit_code: Option<i32>) -> Result<()> {
+    app.proxy
+        .send_event(UserEvent::Exit(exit_code.unwrap_or(0)))
+        .map_err(|_| anyhow!("Failed to send event"))
+}
+
+#[pyframe_api]
+fn block_quit(blocked: bool) -> Result<()> {
+    app.lifecycle()?.quit_blocked = blocked;
+    Ok(())
+}
+
+/// Antwort eines `app.beforeQuit`-Handlers; `prevent` bricht das Beenden ab
+#[pyframe_api]
+fn before_quit_reply(quit_id: u32, prevent: Option<bool>) -> Result<()> {
+    app.reply_before_quit(quit_id, prevent.unwrap_or(false))
+}
+
+#[pyframe_api]
+fn shutdown_complete() -> Result<()> {
+    let exit_code = app.lifecycle()?.exit_code.unwrap_or(0);
+    app.proxy
+        .send_event(UserEvent::ShutdownComplete(exit_code))
+        .map_err(|_| anyhow!("Failed to send event"))
+}
+
+#[pyframe_api]
+fn show_main_window() -> Result<()> {
+    app.proxy
+        .send_event(UserEvent::ShowMainWindow)
+        .map_err(|_| anyhow!("Failed to send event"))
+}
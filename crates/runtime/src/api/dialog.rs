@@ -92,7 +92,11 @@ fn pick_dir(start_dir: Option<String>) -> Result<Value> {
     let dialog = _create_dialog(parent, None, start_dir);
 
     match dialog.pick_folder() {
-        Some(dir) => Ok(json!(dir)),
+        Some(dir) => {
+            // Vom Nutzer gewählte Ordner darf die Seite über `pyframe://filesystem/` lesen
+            app.fs_scope()?.allow(dir.clone());
+            Ok(json!(dir))
+        }
         None => Ok(json!(null)),
     }
 }
@@ -103,7 +107,13 @@ fn pick_dirs(start_dir: Option<String>) -> Result<Value> {
     let dialog = _create_dialog(parent, None, start_dir);
 
     match dialog.pick_folders() {
-        Some(dirs) => Ok(json!(dirs)),
+        Some(dirs) => {
+            let mut scope = app.fs_scope()?;
+            for dir in &dirs {
+                scope.allow(dir.clone());
+            }
+            Ok(json!(dirs))
+        }
         None => Ok(json!(null)),
     }
 }
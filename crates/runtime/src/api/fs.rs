@@ -0,0 +1,412 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    api_manager::ApiManager,
+    file_guard::{self, OperationCancelled},
+    fs_scope::{BaseDir, FsScope, ScopeDenied},
+    fs_watch::WatchOptions,
+    log_err, CoreApplication,
+};
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use pyframe_macros::pyframe_api;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Größter Block für `fs.readChunk`, größere Dateien werden in mehreren Aufrufen gelesen.
+const MAX_CHUNK: u64 = 4 * 1024 * 1024;
+
+pub fn register_api_instances(_api_manager: &mut ApiManager) {
+    _api_manager.register_async_api("fs.readFile", read_file);
+    _api_manager.register_async_api("fs.writeFile", write_file);
+    _api_manager.register_async_api("fs.appendFile", append_file);
+    _api_manager.register_async_api("fs.readChunk", read_chunk);
+    _api_manager.register_async_api("fs.writeChunk", write_chunk);
+    _api_manager.register_async_api("fs.exists", exists);
+    _api_manager.register_async_api("fs.stat", stat);
+    _api_manager.register_async_api("fs.readDir", read_dir);
+    _api_manager.register_async_api("fs.mkdir", mkdir);
+    _api_manager.register_async_api("fs.rename", rename);
+    _api_manager.register_async_api("fs.copy", copy);
+    _api_manager.register_async_api("fs.remove", remove);
+    _api_manager.register_async_api("fs.watch", watch);
+    _api_manager.register_async_api("fs.unwatch", unwatch);
+}
+
+#[derive(Deserialize, Clone, Copy)]
+enum Encoding {
+    #[serde(rename = "utf8")]
+    Utf8,
+    #[serde(rename = "base64")]
+    Base64,
+}
+
+impl Encoding {
+    fn encode(self, data: Vec<u8>) -> Result<String> {
+        match self {
+            Encoding::Utf8 => Ok(String::from_utf8(data)?),
+            Encoding::Base64 => Ok(STANDARD.encode(data)),
+        }
+    }
+
+    fn decode(self, data: String) -> Result<Vec<u8>> {
+        match self {
+            Encoding::Utf8 => Ok(data.into_bytes()),
+            Encoding::Base64 => Ok(STANDARD.decode(data)?),
+        }
+    }
+}
+
+#[derive(Serialize)]
+enum FileType {
+    #[serde(rename = "file")]
+    File,
+    #[serde(rename = "dir")]
+    Dir,
+    #[serde(rename = "symlink")]
+    Symlink,
+    #[serde(rename = "other")]
+    Other,
+}
+
+impl From<fs::FileType> for FileType {
+    fn from(file_type: fs::FileType) -> Self {
+        if file_type.is_symlink() {
+            FileType::Symlink
+        } else if file_type.is_dir() {
+            FileType::Dir
+        } else if file_type.is_file() {
+            FileType::File
+        } else {
+            FileType::Other
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FileStat {
+    #[serde(rename = "type")]
+    file_type: FileType,
+    size: u64,
+    /// Milliseconds since the Unix epoch, `None` where the platform does not record it.
+    modified: Option<u64>,
+    accessed: Option<u64>,
+    created: Option<u64>,
+    readonly: bool,
+    /// Unix permission bits, `None` on Windows.
+    mode: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct DirEntry {
+    name: String,
+    #[serde(rename = "type")]
+    file_type: FileType,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Chunk {
+    /// Base64 encoded.
+    data: String,
+    bytes_read: u64,
+    eof: bool,
+}
+
+/// [`FsScope::resolve`] for paths that must exist, [`FsScope::resolve_target`] for the others.
+type Resolver = fn(&FsScope, &Path) -> Result<PathBuf>;
+
+/// Reads have to lie in `filesystem_scope` (with the picked directories) or in the write scope.
+/// Everything else fails with [`ScopeDenied`], like on the `filesystem` host.
+fn resolve_read(scopes: &[&FsScope], path: &Path, resolve: Resolver) -> Result<PathBuf> {
+    for scope in scopes {
+        match resolve(scope, path) {
+            Err(err) if err.is::<ScopeDenied>() => continue,
+            result => return result,
+        }
+    }
+    Err(ScopeDenied(path.to_path_buf()).into())
+}
+
+fn readable(app: &CoreApplication, path: &Path, resolve: Resolver) -> Result<PathBuf> {
+    let (scope, guard) = (app.fs_scope()?, app.file_guard());
+    resolve_read(&[&*scope, guard.scope()], path, resolve)
+}
+
+fn millis(time: std::io::Result<SystemTime>) -> Option<u64> {
+    let duration = time.ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some(duration.as_millis() as u64)
+}
+
+#[cfg(unix)]
+fn mode(metadata: &fs::Metadata) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+    Some(metadata.permissions().mode() & 0o7777)
+}
+
+#[cfg(not(unix))]
+fn mode(_metadata: &fs::Metadata) -> Option<u32> {
+    None
+}
+
+/// Liest eine Datei als UTF-8 (Standard) oder Base64
+#[pyframe_api]
+fn read_file(base: BaseDir, path: String, encoding: Option<Encoding>) -> Result<String> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    let path = readable(&app, &path, FsScope::resolve)?;
+    encoding.unwrap_or(Encoding::Utf8).encode(fs::read(path)?)
+}
+
+/// Schreibt eine Datei, fehlende Elternverzeichnisse werden angelegt
+#[pyframe_api]
+fn write_file(base: BaseDir, path: String, contents: String, encoding: Option<Encoding>) -> Result<()> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    let contents = encoding.unwrap_or(Encoding::Utf8).decode(contents)?;
+    let guard = app.file_guard();
+    guard.audited(&window, "fs.writeFile", &path, None, || {
+        let path = guard.check(&path)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    })
+}
+
+/// Hängt an eine Datei an und legt sie bei Bedarf an
+#[pyframe_api]
+fn append_file(base: BaseDir, path: String, contents: String, encoding: Option<Encoding>) -> Result<()> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    let contents = encoding.unwrap_or(Encoding::Utf8).decode(contents)?;
+    let guard = app.file_guard();
+    guard.audited(&window, "fs.appendFile", &path, None, || {
+        let path = guard.check(&path)?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        file.write_all(&contents)?;
+        Ok(())
+    })
+}
+
+/// Liest höchstens `length` Bytes ab `offset`, Base64-kodiert
+#[pyframe_api]
+fn read_chunk(base: BaseDir, path: String, offset: u64, length: u64) -> Result<Chunk> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    let path = readable(&app, &path, FsScope::resolve)?;
+    let mut file = fs::File::open(path)?;
+    let total = file.metadata()?.len();
+    file.seek(SeekFrom::Start(offset.min(total)))?;
+    let mut data = Vec::new();
+    file.take(length.min(MAX_CHUNK)).read_to_end(&mut data)?;
+    let bytes_read = data.len() as u64;
+    Ok(Chunk {
+        data: STANDARD.encode(data),
+        bytes_read,
+        eof: offset.saturating_add(bytes_read) >= total,
+    })
+}
+
+/// Schreibt Base64-Daten ab `offset`; `truncate` kürzt die Datei vorher auf `offset`
+#[pyframe_api]
+fn write_chunk(base: BaseDir, path: String, offset: u64, data: String, truncate: Option<bool>) -> Result<()> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    let data = STANDARD.decode(data)?;
+    let guard = app.file_guard();
+    guard.audited(&window, "fs.writeChunk", &path, None, || {
+        let path = guard.check(&path)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).truncate(false).write(true).open(path)?;
+        if truncate.unwrap_or(false) {
+            file.set_len(offset)?;
+        }
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(&data)?;
+        Ok(())
+    })
+}
+
+#[pyframe_api]
+fn exists(base: BaseDir, path: String) -> Result<bool> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    Ok(readable(&app, &path, FsScope::resolve_target)?.exists())
+}
+
+/// Metadaten ohne Symlinks aufzulösen
+#[pyframe_api]
+fn stat(base: BaseDir, path: String) -> Result<FileStat> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    let path = readable(&app, &path, FsScope::resolve_target)?;
+    let metadata = fs::symlink_metadata(path)?;
+    Ok(FileStat {
+        file_type: metadata.file_type().into(),
+        size: metadata.len(),
+        modified: millis(metadata.modified()),
+        accessed: millis(metadata.accessed()),
+        created: millis(metadata.created()),
+        readonly: metadata.permissions().readonly(),
+        mode: mode(&metadata),
+    })
+}
+
+/// Einträge eines Verzeichnisses mit Typ, nach Namen sortiert
+#[pyframe_api]
+fn read_dir(base: BaseDir, path: String) -> Result<Vec<DirEntry>> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    let path = readable(&app, &path, FsScope::resolve)?;
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        entries.push(DirEntry {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            file_type: entry.file_type()?.into(),
+        });
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+/// Legt ein Verzeichnis an, mit `recursive` wie `mkdir -p`
+#[pyframe_api]
+fn mkdir(base: BaseDir, path: String, recursive: Option<bool>) -> Result<()> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    let guard = app.file_guard();
+    guard.audited(&window, "fs.mkdir", &path, None, || {
+        let path = guard.check(&path)?;
+        match recursive.unwrap_or(false) {
+            true => fs::create_dir_all(path)?,
+            false => fs::create_dir(path)?,
+        }
+        Ok(())
+    })
+}
+
+/// Benennt innerhalb eines Basisverzeichnisses um oder verschiebt
+#[pyframe_api]
+fn rename(base: BaseDir, from: String, to: String) -> Result<()> {
+    let from = base.resolve(&app.launch_info, &from)?;
+    let to = base.resolve(&app.launch_info, &to)?;
+    let guard = app.file_guard();
+    guard.audited(&window, "fs.rename", &from, Some(&to), || {
+        // Die Quelle verschwindet, also müssen beide Seiten im Schreib-Scope liegen
+        let from = guard.check(&from)?;
+        let to = guard.check(&to)?;
+        Ok(fs::rename(from, to)?)
+    })
+}
+
+/// Kopiert eine Datei innerhalb eines Basisverzeichnisses
+#[pyframe_api]
+fn copy(base: BaseDir, from: String, to: String) -> Result<u64> {
+    let from = base.resolve(&app.launch_info, &from)?;
+    let to = base.resolve(&app.launch_info, &to)?;
+    let guard = app.file_guard();
+    guard.audited(&window, "fs.copy", &from, Some(&to), || {
+        let from = readable(&app, &from, FsScope::resolve)?;
+        let to = guard.check(&to)?;
+        Ok(fs::copy(&from, to)?)
+    })
+}
+
+/// Entfernt eine Datei oder ein leeres Verzeichnis, mit `recursive` auch gefüllte
+#[pyframe_api]
+fn remove(base: BaseDir, path: String, recursive: Option<bool>) -> Result<()> {
+    let path = base.resolve(&app.launch_info, &path)?;
+    if path == base.path(&app.launch_info)? {
+        return Err(anyhow!("The base directory itself cannot be removed."));
+    }
+    let guard = app.file_guard();
+    guard.audited(&window, "fs.remove", &path, None, || {
+        let path = guard.check_delete(&path)?;
+        let metadata = fs::symlink_metadata(&path)?;
+        match (metadata.is_dir(), recursive.unwrap_or(false)) {
+            (true, true) => {
+                if guard.needs_confirmation(&path) && !file_guard::confirm_delete(&window, &path)? {
+                    return Err(OperationCancelled(path).into());
+                }
+                fs::remove_dir_all(&path)?
+            }
+            (true, false) => fs::remove_dir(&path)?,
+            (false, _) => fs::remove_file(&path)?,
+        }
+        Ok(())
+    })
+}
+
+fn patterns(patterns: Option<Vec<String>>) -> Vec<glob::Pattern> {
+    patterns
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|pattern| match glob::Pattern::new(pattern) {
+            Ok(pattern) => Some(pattern),
+            Err(err) => {
+                log_err!(format!("Invalid watch pattern '{}': {}", pattern, err));
+                None
+            }
+        })
+        .collect()
+}
+
+/// Beobachtet eine Datei oder ein Verzeichnis und sendet gebündelte `fs.changed`-Events
+/// an dieses Fenster, bis `fs.unwatch` aufgerufen oder das Fenster geschlossen wird
+#[pyframe_api]
+fn watch(
+    base: BaseDir,
+    path: String,
+    recursive: Option<bool>,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    debounce_ms: Option<u64>,
+) -> Result<u32> {
+    let target = base.resolve(&app.launch_info, &path)?;
+    let target = readable(&app, &target, FsScope::resolve)?;
+    let options = WatchOptions {
+        recursive: recursive.unwrap_or(false),
+        include: patterns(include),
+        exclude: patterns(exclude),
+        debounce: debounce_ms.map(Duration::from_millis),
+    };
+    app.fs_watch()?
+        .watch(app.clone(), window.id, base.path(&app.launch_info)?, &target, options)
+}
+
+#[pyframe_api]
+fn unwatch(id: u32) -> Result<()> {
+    app.fs_watch()?.unwatch(window.id, id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_outside_every_scope_are_denied() {
+        let root = std::env::temp_dir().join(format!("pyframe-fs-read-{}", std::process::id()));
+        let (inside, outside) = (root.join("scope"), root.join("secret"));
+        fs::create_dir_all(&inside).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(inside.join("a.txt"), "a").unwrap();
+        fs::write(outside.join("id_rsa"), "key").unwrap();
+
+        let mut read_scope = FsScope::default();
+        read_scope.allow(inside.clone());
+        let write_scope = FsScope::default();
+        let scopes = [&read_scope, &write_scope];
+
+        assert!(resolve_read(&scopes, &inside.join("a.txt"), FsScope::resolve).is_ok());
+        let denied = resolve_read(&scopes, &outside.join("id_rsa"), FsScope::resolve).unwrap_err();
+        assert!(denied.is::<ScopeDenied>());
+        let denied = resolve_read(&scopes, &inside.join("../secret/id_rsa"), FsScope::resolve_target).unwrap_err();
+        assert!(denied.is::<ScopeDenied>());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
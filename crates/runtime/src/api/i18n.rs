@@ -0,0 +1,57 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::api_manager::ApiManager;
+use anyhow::Result;
+use pyframe_macros::pyframe_api;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+pub fn register_api_instances(_api_manager: &mut ApiManager) {
+    _api_manager.register_async_api("i18n.t", translate);
+    _api_manager.register_async_api("i18n.locale", locale);
+    _api_manager.register_async_api("i18n.setLocale", set_locale);
+    _api_manager.register_async_api("i18n.available", available);
+    _api_manager.register_async_api("i18n.messages", messages);
+}
+
+#[derive(Serialize)]
+struct LocaleInfo {
+    locale: String,
+    /// Locales tried in order, e.g. `["de-AT", "de", "en"]`.
+    chain: Vec<String>,
+}
+
+/// Übersetzt einen Schlüssel; `count` wählt die Pluralform
+#[pyframe_api]
+fn translate(key: String, args: Option<Map<String, Value>>, count: Option<i64>) -> Result<String> {
+    Ok(app.i18n()?.translate(&key, args.as_ref(), count))
+}
+
+#[pyframe_api]
+fn locale() -> Result<LocaleInfo> {
+    let i18n = app.i18n()?;
+    Ok(LocaleInfo {
+        locale: i18n.locale().to_string(),
+        chain: i18n.chain(),
+    })
+}
+
+/// Setzt die Sprache, ohne Angabe wieder die des Systems; alle Fenster erhalten `locale.changed`
+#[pyframe_api]
+fn set_locale(locale: Option<String>) -> Result<String> {
+    app.set_locale(locale.as_deref())
+}
+
+/// Sprachen, für die ein Katalog vorhanden ist
+#[pyframe_api]
+fn available() -> Result<Vec<String>> {
+    app.i18n()?.available()
+}
+
+/// Alle Texte der aktuellen Fallback-Kette, z. B. für eine i18n-Bibliothek in der Seite
+#[pyframe_api]
+fn messages() -> Result<Map<String, Value>> {
+    Ok(app.i18n()?.messages())
+}
@@ -3,8 +3,11 @@
 // SPDX-License-Identifier: MIT
 
 use crate::api_manager::ApiManager;
+pub mod app;
 pub mod dialog;
 pub mod extra;
+pub mod fs;
+pub mod i18n;
 pub mod monitor;
 pub mod notifications;
 pub mod resource;
@@ -13,11 +16,14 @@ pub mod webview;
 pub mod window;
 pub mod window_extra;
 pub fn register_api_instances(_api_manager: &mut ApiManager) {
+    app::register_api_instances(_api_manager);
     shortcut::register_api_instances(_api_manager);
     dialog::register_api_instances(_api_manager);
     window::register_api_instances(_api_manager);
     webview::register_api_instances(_api_manager);
     resource::register_api_instances(_api_manager);
+    fs::register_api_instances(_api_manager);
+    i18n::register_api_instances(_api_manager);
     window_extra::register_api_instances(_api_manager);
     monitor::register_api_instances(_api_manager);
     //#[cfg(target_os = "windows")]
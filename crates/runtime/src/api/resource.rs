@@ -3,6 +3,8 @@
 // SPDX-License-Identifier: MIT
 
 use crate::api_manager::ApiManager;
+use crate::file_guard::{self, OperationCancelled};
+use crate::resource_manager::{zip_resource::ZipResource, FileSystemResource, ResourceManager, ResourceMetadata};
 use anyhow::Result;
 use base64::engine::general_purpose::STANDARD;
 use base64::Engine;
@@ -15,8 +17,10 @@ use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
 use std::io::Cursor;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::channel;
 use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, Instant};
 use walkdir::WalkDir;
@@ -39,6 +43,11 @@ pub fn register_api_instances(_api_manager: &mut ApiManager) {
     _api_manager.register_async_api("resource.translate", translate);
     _api_manager.register_async_api("resource.bundle", bundle);
     _api_manager.register_async_api("resource.thumbnail", thumbnail);
+    _api_manager.register_async_api("resource.files", files);
+    _api_manager.register_async_api("resource.mount", mount);
+    _api_manager.register_async_api("resource.unmount", unmount);
+    _api_manager.register_async_api("resource.layers", layers);
+    _api_manager.register_async_api("resource.info", info);
 }
 
 /// Unterstützte Kodierungsarten für das Lesen
@@ -54,6 +63,7 @@ enum EncodeType {
 
 /// Beobachtet eine Datei und sendet bei Änderung einen HTTP-POST an die gegebene URL.
 /// Bricht nach `max_events` oder `timeout_secs` ab.
+/// Für neue Anwendungen `fs.watch` verwenden, das Events direkt an das Fenster sendet.
 #[pyframe_api]
 fn watch(path: String, callback_url: String, max_events: usize, timeout_secs: u64) -> Result<()> {
     let (tx, rx) = channel();
@@ -93,6 +103,7 @@ fn watch(path: String, callback_url: String, max_events: usize, timeout_secs: u6
 }
 
 /// 🔤 Übersetzungsschlüssel aus JSON-Dateien extrahieren
+/// Für Kataloge mit Fallback und Pluralformen `i18n.t` verwenden.
 #[pyframe_api]
 fn translate(lang_path: String, key: String) -> Result<String> {
     let content = app.resource().load(&lang_path)?;
@@ -124,6 +135,46 @@ fn thumbnail(path: String, max_size: u32) -> Result<String> {
 
     Ok(STANDARD.encode(out))
 }
+/// Alle Dateien über alle Ebenen, optional auf ein Präfix beschränkt
+#[pyframe_api]
+fn files(prefix: Option<String>) -> Result<Vec<String>> {
+    let files = app.resource().list()?;
+    Ok(match prefix {
+        Some(prefix) => files.into_iter().filter(|file| file.starts_with(&prefix)).collect(),
+        None => files,
+    })
+}
+
+/// Legt ein Verzeichnis oder ein Zip-Archiv aus dem `filesystem_scope` als oberste Ebene
+/// über die Ressourcen, optional unter einem URL-Präfix
+#[pyframe_api]
+fn mount(id: String, path: String, prefix: Option<String>) -> Result<()> {
+    let path = app.fs_scope()?.resolve(Path::new(&path))?;
+    let manager: Arc<dyn ResourceManager> = match path.is_file() {
+        true => ZipResource::new(&path)?,
+        false => FileSystemResource::new(&path)?,
+    };
+    app.resource_layers().mount(&id, prefix.as_deref(), manager)
+}
+
+/// Größe, Kompression und Änderungszeit einer Ressource
+#[pyframe_api]
+fn info(path: String) -> Result<ResourceMetadata> {
+    app.resource().metadata(&path)
+}
+
+/// Entfernt eine zuvor eingehängte Ebene
+#[pyframe_api]
+fn unmount(id: String) -> Result<()> {
+    app.resource_layers().unmount(&id)
+}
+
+/// Ebenen von unten nach oben
+#[pyframe_api]
+fn layers() -> Result<Vec<String>> {
+    Ok(app.resource_layers().layer_ids())
+}
+
 /// Prüft, ob eine Ressource existiert
 #[pyframe_api]
 fn exists(path: String) -> Result<bool> {
@@ -143,12 +194,17 @@ fn read(path: String, encode: Option<EncodeType>) -> Result<String> {
     Ok(content)
 }
 
-/// Extrahiert (kopiert) eine Ressource in ein Zielverzeichnis
+/// Extrahiert (kopiert) eine Ressource in ein Zielverzeichnis innerhalb des Schreib-Scopes
 #[pyframe_api]
 fn extract(from: String, to: String) -> Result<()> {
-    let content = app.resource().load(&from)?;
-    fs::write(to, content)?;
-    Ok(())
+    let guard = app.file_guard();
+    let to = PathBuf::from(to);
+    guard.audited(&window, "resource.extract", Path::new(&from), Some(&to), || {
+        let to = guard.check(&to)?;
+        let content = app.resource().load(&from)?;
+        fs::write(to, content)?;
+        Ok(())
+    })
 }
 
 /// Gibt grundlegende Metadaten der Datei zurück
@@ -185,22 +241,35 @@ fn list_recursive(path: String) -> Result<Vec<String>> {
     Ok(entries)
 }
 
-/// Löscht eine Datei oder ein Verzeichnis rekursiv
+/// Löscht eine Datei oder ein Verzeichnis rekursiv, nur innerhalb des Schreib-Scopes
 #[pyframe_api]
 fn delete(path: String) -> Result<()> {
-    if fs::metadata(&path)?.is_dir() {
-        fs::remove_dir_all(path)?;
-    } else {
-        fs::remove_file(path)?;
-    }
-    Ok(())
+    let guard = app.file_guard();
+    let path = PathBuf::from(path);
+    guard.audited(&window, "resource.delete", &path, None, || {
+        let path = guard.check_delete(&path)?;
+        if fs::symlink_metadata(&path)?.is_dir() {
+            if guard.needs_confirmation(&path) && !file_guard::confirm_delete(&window, &path)? {
+                return Err(OperationCancelled(path).into());
+            }
+            fs::remove_dir_all(path)?;
+        } else {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    })
 }
 
-/// Kopiert eine Datei von A nach B
+/// Kopiert eine Datei von A nach B, B muss im Schreib-Scope liegen
 #[pyframe_api]
 fn copy(from: String, to: String) -> Result<()> {
-    fs::copy(from, to)?;
-    Ok(())
+    let guard = app.file_guard();
+    let to = PathBuf::from(to);
+    guard.audited(&window, "resource.copy", Path::new(&from), Some(&to), || {
+        let to = guard.check(&to)?;
+        fs::copy(&from, to)?;
+        Ok(())
+    })
 }
 
 /// Liest eine Datei und gibt den Inhalt hex-kodiert zurück
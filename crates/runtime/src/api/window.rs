@@ -6,15 +6,13 @@ use anyhow::{anyhow, Result};
 use pyframe_macros::{pyframe_api, pyframe_event_api};
 use serde_json::{json, Value};
 
-use tao::{
-    event_loop::ControlFlow,
-    window::{CursorIcon, Fullscreen, Theme, UserAttentionType},
-};
+use tao::window::{CursorIcon, Fullscreen, Theme, UserAttentionType};
 
 use crate::{
     api_manager::ApiManager,
     lock, logical, logical_try,
     options::window::{Position, Size, WindowConfig},
+    utils::UserEvent,
 };
 
 macro_rules! match_window {
@@ -81,6 +79,9 @@ pub fn register_api_instances(_api_manager: &mut ApiManager) {
     _api_manager.register_api("window.setIgnoreCursorEvents", set_ignore_cursor_events);
     _api_manager.register_api("window.theme", theme);
     _api_manager.register_api("window.blockCloseRequested", block_close_requested);
+    _api_manager.register_api("window.subscribe", subscribe);
+    _api_manager.register_api("window.unsubscribe", unsubscribe);
+    _api_manager.register_api("window.subscriptions", subscriptions);
 }
 
 #[pyframe_api]
@@ -98,7 +99,9 @@ fn open(options: Option<WindowConfig>) -> Result<u8> {
 fn close(id: Option<u8>) -> Result<()> {
     let id = id.unwrap_or(window.id);
     if id == 0 {
-        *control_flow = ControlFlow::Exit;
+        app.proxy
+            .send_event(UserEvent::RequestQuit(0))
+            .map_err(|_| anyhow!("Failed to send event"))?;
         return Ok(());
     }
     app.window()?.close_window(id)
@@ -505,3 +508,30 @@ fn block_close_requested(blocked: bool, id: Option<u8>) -> Result<()> {
     state.is_block_closed_requested = blocked;
     Ok(())
 }
+
+#[pyframe_api]
+fn subscribe(events: Vec<String>, id: Option<u8>) -> Result<()> {
+    match_window!(app, window, id);
+    let mut state = lock!(window.state)?;
+    for event in events {
+        if !state.subscriptions.contains(&event) {
+            state.subscriptions.push(event);
+        }
+    }
+    Ok(())
+}
+
+#[pyframe_api]
+fn unsubscribe(events: Vec<String>, id: Option<u8>) -> Result<()> {
+    match_window!(app, window, id);
+    let mut state = lock!(window.state)?;
+    state.subscriptions.retain(|event| !events.contains(event));
+    Ok(())
+}
+
+#[pyframe_api]
+fn subscriptions(id: Option<u8>) -> Result<Vec<String>> {
+    match_window!(app, window, id);
+    let state = lock!(window.state)?;
+    Ok(state.subscriptions.clone())
+}
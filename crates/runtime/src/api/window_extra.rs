@@ -72,7 +72,7 @@ fn set_enable(enabled: bool, id: Option<u8>) -> Result<()> {
 #[pyframe_api]
 fn set_taskbar_icon(taskbar_icon: String, id: Option<u8>) -> Result<()> {
     match_window!(app, window, id);
-    let taskbar_icon = app.resource().load_icon(&taskbar_icon)?;
+    let taskbar_icon = app.resource().load_icon(&taskbar_icon, window.scale_factor())?;
     window.set_taskbar_icon(Some(taskbar_icon));
     Ok(())
 }
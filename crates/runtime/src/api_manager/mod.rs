@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: MIT
 
 mod thread_pool;
-use self::thread_pool::ThreadPool;
+pub use self::thread_pool::ThreadPool;
 use crate::{
     lock_force,
     options::AppOptions,
@@ -87,6 +87,11 @@ impl ApiManager {
         self.app = Some(app);
     }
 
+    /// The workers behind `register_async_api`, shared with other blocking work like protocol requests.
+    pub fn thread_pool(&self) -> ArcMut<ThreadPool> {
+        self.thread_pool.clone()
+    }
+
     pub fn register_async_api<S: Into<String>, T: Serialize + 'static>(
         &mut self,
         name: S,
@@ -6,3 +6,4 @@ pub static INITIALIZE_SCRIPT: &str = include_str!("../../assets/initialize_scrip
 pub static SOCKETIO_SCRIPT: &str = include_str!("../../assets/socketio/socket.io.min.js");
 pub static DEFAULT_LOGO: &[u8] = include_bytes!("../../assets/icon.png");
 pub static INITIALIZEPY_SCRIPT: &str = include_str!("../../assets/py.js");
+pub static SPLASH_SCRIPT: &str = include_str!("../../assets/splash.js");
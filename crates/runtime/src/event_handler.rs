@@ -4,8 +4,15 @@
 
 use crate::{
     hylper::{hit_test, HitTestResult},
-    lock, try_or_log_err,
-    utils::{get_json_sync, FrameEvent, FrameWindowTarget, UserEvent},
+    lock, log_err, log_if_err,
+    options::{
+        menu::SystemTray,
+        window::{WindowConfig, WindowShowWhen},
+        CloseBehavior,
+    },
+    splash::SplashScreen,
+    try_or_log_err,
+    utils::{FrameEvent, FrameWindowTarget, UserEvent},
     CoreApplication,
 };
 use anyhow::Result;
@@ -19,16 +26,27 @@ use tao::{
 
 pub struct EventHandler {
     app: Arc<CoreApplication>,
-    _window_id: WindowId,
+    _window_id: Option<WindowId>,
     tray_icon: Option<tray_icon::TrayIcon>,
+    splash: Option<SplashScreen>,
+    /// Config of the main window after `CloseBehavior::KeepRunning` closed it, with its last geometry.
+    closed_main_window: Option<WindowConfig>,
+    /// `app.ready` wird nur einmal gesendet.
+    app_ready: bool,
+    /// `GlobalHotKeyEvent` id of `reopen_shortcut`.
+    reopen_hotkey: Option<u32>,
 }
 
 impl EventHandler {
-    pub fn new(app: Arc<CoreApplication>, _window_id: WindowId) -> Self {
+    pub fn new(app: Arc<CoreApplication>, _window_id: Option<WindowId>, splash: Option<SplashScreen>) -> Self {
         Self {
             app,
             _window_id,
             tray_icon: None,
+            splash,
+            closed_main_window: None,
+            app_ready: false,
+            reopen_hotkey: None,
         }
     }
 
@@ -38,19 +56,42 @@ impl EventHandler {
             match event {
                 Event::NewEvents(tao::event::StartCause::Init) => {
                     println!("PyFrame Startet Up");
+                    // Tray- und Hotkey-Events wie die Menü-Events über die Event-Loop zustellen
+                    let proxy = self.app.proxy.clone();
+                    tray_icon::TrayIconEvent::set_event_handler(Some(move |event| {
+                        let _ = proxy.send_event(UserEvent::TrayIconEvent(event));
+                    }));
+                    let proxy = self.app.proxy.clone();
+                    global_hotkey::GlobalHotKeyEvent::set_event_handler(Some(move |event| {
+                        let _ = proxy.send_event(UserEvent::HotKeyEvent(event));
+                    }));
                     if self.tray_icon.is_none() {
-                        let tray = self.create_tray_icon()?;
+                        let tray = self.create_tray_icon(crate::utils::primary_scale_factor(target))?;
                         self.tray_icon = tray;
                     }
+                    self.register_reopen_shortcut()?;
                 }
-                Event::WindowEvent { event, window_id, .. } => {
-                    self.handle_window_event(event, window_id, control_flow)?
-                }
+                Event::Suspended => self.app.broadcast_event("app.suspended", json!(null))?,
+                Event::Resumed => self.app.broadcast_event("app.resumed", json!(null))?,
+                Event::WindowEvent { window_id, .. }
+                    if self
+                        .splash
+                        .as_ref()
+                        .is_some_and(|splash| splash.window_id() == window_id) => {}
+                Event::WindowEvent { event, window_id, .. } => self.handle_window_event(event, window_id)?,
                 Event::UserEvent(user_event) => match user_event {
                     UserEvent::FrameEvent(callback) => self.handle_user_event(callback, target, control_flow)?,
                     UserEvent::MenuEvent(_menu_event) => {
                         self.handle_menu_event(_menu_event)?;
                     }
+                    UserEvent::HotKeyEvent(event) => {
+                        if Some(event.id()) == self.reopen_hotkey
+                            && event.state() == global_hotkey::HotKeyState::Pressed
+                        {
+                            self.show_main_window(target)?;
+                        }
+                    }
+                    UserEvent::TrayIconEvent(event) => self.handle_tray_event(event, target)?,
                     UserEvent::Minimize(id) => {
                         let binding = self.app.window()?;
                         let window = binding.get_window_inner(id)?;
@@ -61,27 +102,18 @@ impl EventHandler {
                         let window = binding.get_window_inner(id)?;
                         window.set_maximized(!window.is_maximized());
                     }
-                    UserEvent::CloseWindow => {
-                        let host = self.app.launch_info.options.host.clone();
-                        let port = self.app.launch_info.options.port;
-                        let server_url = format!("http://{}:{}/server_shutdown", host, port);
-
-                        match get_json_sync(&server_url) {
-                            Ok(json) => {
-                                if json["status"] == 200 {
-                                    if let Some(tray) = self.tray_icon.take() {
-                                        drop(tray); // Explizit "destroy"
-                                    }
-                                    *control_flow = ControlFlow::Exit;
-                                } else {
-                                    eprintln!("Shutdown-Status: {:?}", json);
-                                }
-                            }
-                            Err(err) => {
-                                eprintln!("Fehler beim Server-Shutdown: {}", err);
-                            }
+                    UserEvent::CloseWindow => self.quit(0)?,
+                    UserEvent::RequestQuit(exit_code) => self.quit(exit_code)?,
+                    UserEvent::FinishQuit(quit_id) => {
+                        if let Some(exit_code) = self.app.finish_quit(quit_id)? {
+                            self.exit(exit_code)?;
                         }
                     }
+                    UserEvent::Exit(exit_code) => self.exit(exit_code)?,
+                    UserEvent::ShutdownComplete(exit_code) => {
+                        *control_flow = ControlFlow::ExitWithCode(exit_code);
+                    }
+                    UserEvent::CloseRequested(id) => self.handle_window_event(WindowEvent::CloseRequested, id)?,
                     UserEvent::DragWindow(id) => {
                         let binding = self.app.window()?;
                         let window = binding.get_window_inner(id)?;
@@ -91,7 +123,7 @@ impl EventHandler {
                     UserEvent::MouseDown(id, x, y) => {
                         let binding = self.app.window()?;
                         let window = binding.get_window_inner(id)?;
-                        let res = hit_test(window.inner_size(), x, y, window.scale_factor());
+                        let res = hit_test(window.inner_size(), x, y, window.scale_factor(), window.resize_inset);
                         match res {
                             HitTestResult::Client | HitTestResult::NoWhere => {}
                             _ => res.drag_resize_window(&window),
@@ -100,8 +132,36 @@ impl EventHandler {
                     UserEvent::MouseMove(id, x, y) => {
                         let binding = self.app.window()?;
                         let window = binding.get_window_inner(id)?;
-                        hit_test(window.inner_size(), x, y, window.scale_factor()).change_cursor(&window);
+                        hit_test(window.inner_size(), x, y, window.scale_factor(), window.resize_inset)
+                            .change_cursor(&window);
+                    }
+                    UserEvent::BackendReady(settings) => {
+                        *self.app.socket_settings()? = settings;
+                        let options = self.app.launch_info.options.window.clone();
+                        let main_win = self.app.window()?.open_window(target, &options)?;
+                        self._window_id = Some(main_win.id());
+                    }
+                    UserEvent::BackendFailed(err) => {
+                        log_err!(format!("Backend handshake failed: {}", err));
+                        self.splash.take();
+                        *control_flow = ControlFlow::ExitWithCode(1);
                     }
+                    UserEvent::SplashProgress(message) => {
+                        if let Some(splash) = &self.splash {
+                            splash.set_progress(&message)?;
+                        }
+                    }
+                    UserEvent::PageLoaded(id) => {
+                        self.handle_ready(id, WindowShowWhen::Load)?;
+                        self.emit_app_ready(id)?;
+                    }
+                    UserEvent::WindowReady(id) => self.handle_ready(id, WindowShowWhen::Ready)?,
+                    UserEvent::CloseSplash => {
+                        self.splash.take();
+                    }
+                    UserEvent::ShowMainWindow => self.show_main_window(target)?,
+                    UserEvent::ResourcesChanged(paths) => self.app.reload_resources(paths)?,
+                    UserEvent::LocaleChanged => self.apply_locale()?,
                 },
 
                 _ => (),
@@ -111,14 +171,14 @@ impl EventHandler {
         });
     }
 
-    fn handle_window_event(
-        &mut self,
-        event: WindowEvent,
-        window_id: WindowId,
-        control_flow: &mut ControlFlow,
-    ) -> Result<()> {
+    fn handle_window_event(&mut self, event: WindowEvent, window_id: WindowId) -> Result<()> {
         if event == WindowEvent::Destroyed {
-            self.app.window()?.close_window_inner(window_id)?;
+            // Nach CloseRequested ist das Fenster meist schon entfernt
+            let mut manager = self.app.window()?;
+            if manager.get_window_inner(window_id).is_ok() {
+                manager.close_window_inner(window_id)?;
+            }
+            return Ok(());
         }
         let window = self.app.window()?.get_window_inner(window_id)?;
 
@@ -126,13 +186,13 @@ impl EventHandler {
             WindowEvent::Focused(focused) => {
                 #[cfg(target_os = "macos")]
                 window.switch_menu();
-                window.send_ipc_event("window.focused", focused)?;
+                window.emit_event("window.focused", focused)?;
             }
             WindowEvent::ScaleFactorChanged {
                 scale_factor,
                 new_inner_size,
             } => {
-                window.send_ipc_event(
+                window.emit_event(
                     "window.scaleFactorChanged",
                     json!({
                         "scaleFactor": scale_factor,
@@ -141,7 +201,7 @@ impl EventHandler {
                 )?;
             }
             WindowEvent::ThemeChanged(theme) => {
-                window.send_ipc_event(
+                window.emit_event(
                     "window.themeChanged",
                     match theme {
                         tao::window::Theme::Dark => "dark",
@@ -150,37 +210,75 @@ impl EventHandler {
                     },
                 )?;
             }
+            WindowEvent::Moved(position) => {
+                window.emit_subscribed_event("window.moved", position.to_logical::<f64>(window.scale_factor()))?;
+            }
+            WindowEvent::Resized(size) => {
+                window.sync_window_state()?;
+                window.emit_subscribed_event("window.resized", size.to_logical::<f64>(window.scale_factor()))?;
+            }
+            WindowEvent::KeyboardInput {
+                event, is_synthetic, ..
+            } => {
+                let key = match event.logical_key.to_text() {
+                    Some(text) => text.to_string(),
+                    None => format!("{:?}", event.logical_key),
+                };
+                window.emit_subscribed_event(
+                    "window.keyboardInput",
+                    json!({
+                        "key": key,
+                        "code": event.physical_key.to_string(),
+                        "text": event.text,
+                        "location": format!("{:?}", event.location),
+                        "state": match event.state {
+                            tao::event::ElementState::Pressed => "pressed",
+                            _ => "released",
+                        },
+                        "repeat": event.repeat,
+                        "isSynthetic": is_synthetic,
+                    }),
+                )?;
+            }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                window.emit_subscribed_event(
+                    "window.modifiersChanged",
+                    json!({
+                        "shift": modifiers.shift_key(),
+                        "control": modifiers.control_key(),
+                        "alt": modifiers.alt_key(),
+                        "super": modifiers.super_key(),
+                    }),
+                )?;
+            }
+            WindowEvent::CursorEntered { .. } => {
+                window.emit_subscribed_event("window.cursorEntered", json!(null))?;
+            }
+            WindowEvent::CursorLeft { .. } => {
+                window.emit_subscribed_event("window.cursorLeft", json!(null))?;
+            }
             WindowEvent::CloseRequested => {
                 println!("CloseRequested");
                 let is_block_closed_requested = { lock!(window.state)?.is_block_closed_requested };
                 println!("is_block_closed_requested:{}", is_block_closed_requested);
                 if is_block_closed_requested {
                     println!("close_1");
-                    window.send_ipc_event("window.closeRequested", json!(null))?;
-                } else {
-                    self.app.window()?.close_window_inner(window_id)?;
-                    if window.id == 0 {
-                        println!("close_2");
-                        let host = self.app.launch_info.options.host.clone();
-                        let port = self.app.launch_info.options.port;
-                        let server_url = format!("http://{}:{}/server_shutdown", host, port);
-
-                        match get_json_sync(&server_url) {
-                            Ok(json) => {
-                                if json["status"] == 200 {
-                                    if let Some(tray) = self.tray_icon.take() {
-                                        drop(tray); // Explizit "destroy"
-                                    }
-                                    *control_flow = ControlFlow::Exit;
-                                } else {
-                                    eprintln!("Shutdown-Status: {:?}", json);
-                                }
-                            }
-                            Err(err) => {
-                                eprintln!("Fehler beim Server-Shutdown: {}", err);
-                            }
+                    window.emit_event("window.closeRequested", json!(null))?;
+                } else if window.id == 0 {
+                    match self.app.launch_info.options.close_behavior() {
+                        // Das Hauptfenster bleibt offen, bis der Shutdown durch ist oder das Beenden blockiert wurde
+                        CloseBehavior::Quit => self.quit(0)?,
+                        CloseBehavior::Hide => {
+                            window.set_visible(false);
+                            window.emit_event("window.hidden", json!(null))?;
+                        }
+                        CloseBehavior::KeepRunning => {
+                            self.closed_main_window = Some(self.main_window_config(&window));
+                            self.app.window()?.close_window_inner(window_id)?;
                         }
                     }
+                } else {
+                    self.app.window()?.close_window_inner(window_id)?;
                 }
             }
             _ => (),
@@ -189,87 +287,211 @@ impl EventHandler {
     }
 
     fn handle_menu_event(&self, menu_event: muda::MenuEvent) -> Result<()> {
-        let binding = self.app.window()?;
+        let payload = {
+            let menu_api = self.app.menu()?;
+            let Some((kind, Some(func_info))) = menu_api.items.get(menu_event.id()) else {
+                return Ok(());
+            };
+            match kind {
+                muda::MenuItemKind::MenuItem(item) => serde_json::json!({
+                    "protocol": "menu",
+                    "payload":{
+                        "command_id":func_info,
+                        "extra_args": [item.id().0.clone()],
+                        "extra_kwargs":{}
+                    }
+                }),
+                muda::MenuItemKind::Submenu(sub) => serde_json::json!({
+                    "event": "menu",
+                    "kind":"submenu",
+                    "command_id": func_info,
+                    "is_enabled":sub.is_enabled()
+                }),
+                muda::MenuItemKind::Predefined(pre) => serde_json::json!({
+                    "event": "menu",
+                    "kind":"predefined",
+                    "command_id": func_info,
+                    "text":pre.text()
+                }),
+                muda::MenuItemKind::Check(check) => serde_json::json!({
+                    "event": "menu",
+                    "kind":"check",
+                    "command_id": func_info,
+                    "checked":check.is_checked()
+                }),
+                muda::MenuItemKind::Icon(icon) => serde_json::json!({
+                    "event": "menu",
+                    "kind":"check",
+                    "command_id": func_info,
+                    "is_enabled":icon.is_enabled()
+                }),
+            }
+        };
 
-        let menu_api = self.app.menu()?;
+        // Nach `CloseBehavior::KeepRunning` ist das Hauptfenster zu, dann ein anderes Fenster oder direkt das Backend
+        let window = {
+            let manager = self.app.window()?;
+            self._window_id
+                .and_then(|id| manager.get_window_inner(id).ok())
+                .or_else(|| manager.list_windows().first().map(|window| Arc::clone(window)))
+        };
+        match window {
+            // window.send_ipc_event("window.menu_comand_handel", json!(payload))?;
+            Some(window) => window.post_message(payload),
+            None => self.app.post_backend_event(None, "menu", payload),
+        }
+    }
 
-        let items = &menu_api.items;
+    /// Emits `app.beforeQuit`; the shutdown follows on `UserEvent::FinishQuit` unless vetoed.
+    fn quit(&mut self, exit_code: i32) -> Result<()> {
+        self.app.request_quit(exit_code)
+    }
 
-        // let window_id: WindowId = self.active_window_id.lock().unwrap().unwrap();
-        let window = binding.get_window_inner(self._window_id)?;
+    /// Retranslates menu and tray labels and emits `locale.changed` everywhere.
+    fn apply_locale(&self) -> Result<()> {
+        self.app.menu()?.translate_labels()?;
+        if let (Some(tray), Some(options)) = (&self.tray_icon, self.tray_options()) {
+            let options = self.translated_tray_options(options)?;
+            #[cfg(not(target_os = "windows"))]
+            tray.set_title(options.title.as_deref());
+            #[cfg(not(target_os = "linux"))]
+            tray.set_tooltip(options.tooltip.as_deref())?;
+        }
+        let payload = {
+            let i18n = self.app.i18n()?;
+            json!({ "locale": i18n.locale(), "chain": i18n.chain() })
+        };
+        self.app.broadcast_event(crate::i18n::LOCALE_CHANGED_EVENT, payload)
+    }
 
-        if let Some((kind, function_info)) = items.get(menu_event.id()) {
-            match kind {
-                muda::MenuItemKind::MenuItem(item) => {
-                    let item_id = item.id().0.clone();
-                    if let Some(func_info) = function_info {
-                        let payload = serde_json::json!({
-                            "protocol": "menu",
-                            "payload":{
-                                "command_id":func_info,
-                                "extra_args": [item_id],
-                                "extra_kwargs":{}
-                            }
-                        });
-
-                        // window.send_ipc_event("window.menu_comand_handel", json!(payload))?;
-                        window.post_message(payload)?;
-                    }
-                }
-                muda::MenuItemKind::Submenu(sub) => {
-                    if let Some(func_info) = function_info {
-                        let payload = serde_json::json!({
-                            "event": "menu",
-                            "kind":"submenu",
-                            "command_id": func_info,
-                            "is_enabled":sub.is_enabled()
-                        });
-                        // window.send_ipc_event("window.menu_comand_handel", json!(payload))?;
-                        window.post_message(payload)?;
-                    }
-                }
-                muda::MenuItemKind::Predefined(pre) => {
-                    if let Some(func_info) = function_info {
-                        let payload = serde_json::json!({
-                            "event": "menu",
-                            "kind":"predefined",
-                            "command_id": func_info,
-                            "text":pre.text()
-                        });
-                        // window.send_ipc_event("window.menu_comand_handel", json!(payload))?;
-                        window.post_message(payload)?;
-                    }
-                }
-                muda::MenuItemKind::Check(check) => {
-                    if let Some(func_info) = function_info {
-                        let payload = serde_json::json!({
-                            "event": "menu",
-                            "kind":"check",
-                            "command_id": func_info,
-                            "checked":check.is_checked()
-                        });
-                        // window.send_ipc_event("window.menu_comand_handel", json!(payload))?;
-                        window.post_message(payload)?;
-                    }
-                }
-                muda::MenuItemKind::Icon(icon) => {
-                    if let Some(func_info) = function_info {
-                        let payload = serde_json::json!({
-                            "event": "menu",
-                            "kind":"check",
-                            "command_id": func_info,
-                            "is_enabled":icon.is_enabled()
-                        });
-                        // window.send_ipc_event("window.menu_comand_handel", json!(payload))?;
-                        window.post_message(payload)?;
-                    }
-                }
+    fn tray_options(&self) -> Option<SystemTray> {
+        self.app.launch_info.options.window_menu.as_ref()?.system_tray.clone()
+    }
+
+    /// Tray options with `i18n:` title and tooltip resolved.
+    fn translated_tray_options(&self, mut options: SystemTray) -> Result<SystemTray> {
+        let mut i18n = self.app.i18n()?;
+        options.title = options.title.map(|title| i18n.label(&title));
+        options.tooltip = options.tooltip.map(|tooltip| i18n.label(&tooltip));
+        Ok(options)
+    }
+
+    fn exit(&mut self, exit_code: i32) -> Result<()> {
+        if let Some(tray) = self.tray_icon.take() {
+            drop(tray); // Explizit "destroy"
+        }
+        self.app.start_shutdown(exit_code)
+    }
+
+    /// Emits `app.ready` once the main window has loaded its page for the first time.
+    fn emit_app_ready(&mut self, window_id: WindowId) -> Result<()> {
+        if self.app_ready || Some(window_id) != self._window_id {
+            return Ok(());
+        }
+        self.app_ready = true;
+        let id = self.app.window()?.get_window_inner(window_id)?.id;
+        self.app.broadcast_event("app.ready", json!({ "mainWindowId": id }))
+    }
+
+    /// Shows the hidden main window again or reopens it with its last geometry.
+    fn show_main_window(&mut self, target: &FrameWindowTarget) -> Result<()> {
+        let existing = self.app.window()?.get_window(0).ok();
+        if let Some(window) = existing {
+            let was_visible = window.is_visible();
+            window.set_visible(true);
+            window.set_minimized(false);
+            window.set_focus();
+            if !was_visible {
+                window.emit_event("window.shown", json!(null))?;
+            }
+            return Ok(());
+        }
+
+        let Some(config) = self.closed_main_window.take() else {
+            return Ok(());
+        };
+        let main_win = self.app.window()?.open_window_with_id(target, 0, &config)?;
+        self._window_id = Some(main_win.id());
+        Ok(())
+    }
+
+    /// Startkonfiguration des Hauptfensters mit der aktuellen Position und Größe.
+    fn main_window_config(&self, window: &crate::window_manager::window::FrameWindow) -> WindowConfig {
+        let mut config = self.app.launch_info.options.window.clone();
+        let scale_factor = window.scale_factor();
+        let maximized = window.is_maximized();
+        if !maximized {
+            if let Ok(position) = window.outer_position() {
+                config.window_position = Some(position.to_logical(scale_factor));
             }
+            config.window_inner_size = Some(window.inner_size().to_logical(scale_factor));
+        }
+        config.window_maximized = Some(maximized);
+        config
+    }
+
+    fn register_reopen_shortcut(&mut self) -> Result<()> {
+        let Some(accelerator) = &self.app.launch_info.options.reopen_shortcut else {
+            return Ok(());
+        };
+        self.reopen_hotkey = Some(self.app.shortcut()?.register_app_hotkey(accelerator)?);
+        Ok(())
+    }
+
+    /// Linksklick auf das Tray-Icon holt das Hauptfenster zurück, sofern dort nicht das Menü aufgeht.
+    fn handle_tray_event(&mut self, event: tray_icon::TrayIconEvent, target: &FrameWindowTarget) -> Result<()> {
+        if !self.tray_icon.as_ref().is_some_and(|tray| tray.id() == event.id()) {
+            return Ok(());
+        }
+        let clicked = matches!(
+            event,
+            tray_icon::TrayIconEvent::Click {
+                button: tray_icon::MouseButton::Left,
+                button_state: tray_icon::MouseButtonState::Up,
+                ..
+            } | tray_icon::TrayIconEvent::DoubleClick { .. }
+        );
+        let menu_on_left_click = || self.tray_options().and_then(|options| options.menu_on_left_click);
+        if clicked && !menu_on_left_click().unwrap_or(false) {
+            self.show_main_window(target)?;
         }
+        Ok(())
+    }
+
+    fn handle_ready(&mut self, window_id: WindowId, signal: WindowShowWhen) -> Result<()> {
+        let window = match self.app.window()?.get_window_inner(window_id) {
+            Ok(window) => window,
+            Err(_) => return Ok(()),
+        };
+        window.mark_ready(signal)?;
 
+        // Splash erst schließen, wenn das Hauptfenster sichtbar ist
+        if Some(window_id) == self._window_id && lock!(window.state)?.pending_show.is_none() {
+            self.close_splash();
+        }
         Ok(())
     }
 
+    /// Schließt den Splash, frühestens nach Ablauf von `min_display_ms`.
+    fn close_splash(&mut self) {
+        let Some(splash) = &self.splash else {
+            return;
+        };
+        let remaining = splash.remaining();
+        if remaining.is_zero() {
+            self.splash.take();
+            return;
+        }
+
+        let proxy = self.app.proxy.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(remaining);
+            log_if_err!(proxy
+                .send_event(UserEvent::CloseSplash)
+                .map_err(|_| anyhow::anyhow!("Failed to send event")));
+        });
+    }
+
     fn handle_user_event(
         &self,
         callback: FrameEvent,
@@ -279,7 +501,8 @@ impl EventHandler {
         callback(target, control_flow)
     }
 
-    fn create_tray_icon(&self) -> Result<Option<tray_icon::TrayIcon>> {
+    /// `scale_factor` of the primary monitor, the tray icon is decoded for it.
+    fn create_tray_icon(&self, scale_factor: f64) -> Result<Option<tray_icon::TrayIcon>> {
         let menu = self.app.menu()?;
         let tray_icon_options = match self.app.launch_info.options.window_menu.clone() {
             Some(menu) => menu.system_tray.clone(),
@@ -297,13 +520,23 @@ impl EventHandler {
             Some(crate::options::MenuMode::Tray) => {
                 if let Some(tra_options) = &tray_icon_options {
                     let window_menu = menu.get_menu_manager()?; // Beispielmethode, die das Fenster-Menü zurückgib
-                    tray_icon = Some(crate::hylper::init_sys_tray(tra_options.clone(), window_menu)?);
+                    tray_icon = Some(crate::hylper::init_sys_tray(
+                        self.app.resource().as_ref(),
+                        self.translated_tray_options(tra_options.clone())?,
+                        window_menu,
+                        scale_factor,
+                    )?);
                 }
             }
             Some(crate::options::MenuMode::MenuAndTray) => {
                 if let Some(tra_options) = &tray_icon_options {
                     let window_menu = menu.get_menu_manager()?; // Beispielmethode, die das Fenster-Menü zurückgib
-                    tray_icon = Some(crate::hylper::init_sys_tray(tra_options.clone(), window_menu)?);
+                    tray_icon = Some(crate::hylper::init_sys_tray(
+                        self.app.resource().as_ref(),
+                        self.translated_tray_options(tra_options.clone())?,
+                        window_menu,
+                        scale_factor,
+                    )?);
                 }
             }
             None => {
@@ -0,0 +1,162 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    fs_scope::{FsScope, ScopeDenied},
+    lock,
+    options::LaunchInfo,
+    window_manager::window::FrameWindow,
+};
+use anyhow::Result;
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const DEFAULT_SCOPE: [&str; 3] = ["$DATA", "$CACHE", "$TEMP"];
+pub const AUDIT_LOG: &str = "audit.log";
+
+/// The user declined the confirmation dialog.
+#[derive(Debug)]
+pub struct OperationCancelled(pub PathBuf);
+
+impl std::fmt::Display for OperationCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Deleting {} was cancelled", self.0.display())
+    }
+}
+
+impl std::error::Error for OperationCancelled {}
+
+/// Write scope, delete confirmation and audit log for the `resource.*` and `fs.*` calls that modify files.
+/// Configured through `AppOptions.file_operations`.
+#[derive(Debug)]
+pub struct FileGuard {
+    scope: FsScope,
+    /// data, cache and temp dir; recursive deletes in there never ask.
+    app_dirs: Vec<PathBuf>,
+    confirm_recursive_delete: bool,
+    audit_path: Option<PathBuf>,
+    audit_file: Mutex<Option<File>>,
+}
+
+impl FileGuard {
+    pub fn new(launch_info: &LaunchInfo) -> Self {
+        let options = launch_info.options.file_operations.clone().unwrap_or_default();
+        let scope = options
+            .scope
+            .unwrap_or_else(|| DEFAULT_SCOPE.iter().map(|entry| entry.to_string()).collect());
+        Self {
+            scope: FsScope::from_entries(&scope, launch_info),
+            app_dirs: vec![
+                launch_info.data_dir.clone(),
+                launch_info.cache_dir.clone(),
+                launch_info.temp_dir.clone(),
+            ],
+            confirm_recursive_delete: options.confirm_recursive_delete.unwrap_or(false),
+            audit_path: options
+                .audit_log
+                .unwrap_or(true)
+                .then(|| launch_info.data_dir.join(AUDIT_LOG)),
+            audit_file: Mutex::new(None),
+        }
+    }
+
+    /// The write scope; whatever may be written may be read as well.
+    pub fn scope(&self) -> &FsScope {
+        &self.scope
+    }
+
+    /// Fails with [`ScopeDenied`] unless `path` may be written or deleted.
+    pub fn check(&self, path: &Path) -> Result<PathBuf> {
+        self.scope.resolve_target(path)
+    }
+
+    /// Like [`Self::check`], but the scope directories themselves may not be deleted.
+    pub fn check_delete(&self, path: &Path) -> Result<PathBuf> {
+        let path = self.check(path)?;
+        if self.scope.is_root(&path) {
+            return Err(ScopeDenied(path).into());
+        }
+        Ok(path)
+    }
+
+    /// Whether deleting the directory `path` has to be confirmed by the user first.
+    pub fn needs_confirmation(&self, path: &Path) -> bool {
+        self.confirm_recursive_delete && !self.app_dirs.iter().any(|dir| path.starts_with(dir))
+    }
+
+    /// Runs `operation` and records its outcome in the audit log.
+    pub fn audited<T>(
+        &self,
+        window: &FrameWindow,
+        name: &str,
+        path: &Path,
+        target: Option<&Path>,
+        operation: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        let result = operation();
+        let outcome = match &result {
+            Ok(_) => "ok".to_string(),
+            Err(err) if err.is::<ScopeDenied>() => "denied".to_string(),
+            Err(err) if err.is::<OperationCancelled>() => "cancelled".to_string(),
+            Err(err) => format!("failed: {}", err),
+        };
+        // Ein nicht schreibbares Log soll die Operation selbst nicht scheitern lassen
+        if let Err(err) = self.record(window.id, name, path, target, &outcome) {
+            log::warn!("Could not write audit log: {err}");
+        }
+        result
+    }
+
+    fn record(&self, window: u8, name: &str, path: &Path, target: Option<&Path>, outcome: &str) -> Result<()> {
+        let Some(audit_path) = &self.audit_path else {
+            return Ok(());
+        };
+        let time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let entry = serde_json::json!({
+            "time": time,
+            "window": window,
+            "operation": name,
+            "path": path,
+            "target": target,
+            "outcome": outcome,
+        });
+        let mut file = lock!(self.audit_file)?;
+        if file.is_none() {
+            if let Some(parent) = audit_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            *file = Some(OpenOptions::new().create(true).append(true).open(audit_path)?);
+        }
+        if let Some(file) = file.as_mut() {
+            writeln!(file, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+/// Asks on the main thread whether the directory `path` may be deleted.
+/// Blocks until answered, so it must only be called from `register_async_api` handlers.
+pub fn confirm_delete(window: &Arc<FrameWindow>, path: &Path) -> Result<bool> {
+    let (sender, receiver) = mpsc::channel();
+    let parent = window.clone();
+    let description = format!("{} and everything in it will be deleted permanently.", path.display());
+    window.send_event(move |_, _| {
+        let answer = rfd::MessageDialog::new()
+            .set_title("Delete folder?")
+            .set_description(&description)
+            .set_level(rfd::MessageLevel::Warning)
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .set_parent(&parent.window)
+            .show();
+        let _ = sender.send(answer == rfd::MessageDialogResult::Yes);
+        Ok(())
+    })?;
+    // Geschlossene Event-Loop zählt als Ablehnung
+    Ok(receiver.recv().unwrap_or(false))
+}
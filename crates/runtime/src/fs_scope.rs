@@ -0,0 +1,328 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::options::LaunchInfo;
+use anyhow::{anyhow, Result};
+use directories::UserDirs;
+use std::path::{Component, Path, PathBuf};
+
+/// Request for a path outside of the allowed directories, answered with `403`.
+#[derive(Debug)]
+pub struct ScopeDenied(pub PathBuf);
+
+impl std::fmt::Display for ScopeDenied {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Access to {} is not allowed", self.0.display())
+    }
+}
+
+impl std::error::Error for ScopeDenied {}
+
+/// Directories the `filesystem` host may serve from.
+/// Entries of `AppOptions.filesystem_scope` may start with `$DATA`, `$CACHE`, `$TEMP`, `$HOME`,
+/// `$DOCUMENTS` or `$DOWNLOADS`; directories picked via `dialog.pickDir` are added at runtime.
+#[derive(Debug, Default)]
+pub struct FsScope {
+    allowed: Vec<PathBuf>,
+}
+
+impl FsScope {
+    pub fn new(launch_info: &LaunchInfo) -> Self {
+        Self::from_entries(launch_info.options.filesystem_scope.iter().flatten(), launch_info)
+    }
+
+    /// Scope from entries in the `filesystem_scope` format, used for the write scope as well.
+    pub fn from_entries<'a>(entries: impl IntoIterator<Item = &'a String>, launch_info: &LaunchInfo) -> Self {
+        let mut scope = Self::default();
+        for entry in entries {
+            match resolve_placeholder(entry, launch_info) {
+                Ok(dir) => scope.allow(dir),
+                Err(err) => log::warn!("Ignoring filesystem scope entry {entry}: {err}"),
+            }
+        }
+        scope
+    }
+
+    pub fn allow(&mut self, dir: PathBuf) {
+        if !self.allowed.contains(&dir) {
+            self.allowed.push(dir);
+        }
+    }
+
+    pub fn allowed(&self) -> &[PathBuf] {
+        &self.allowed
+    }
+
+    /// Resolves `path` to its canonical form if it lies inside an allowed directory.
+    /// Fails with [`ScopeDenied`] for `..` components and for symlinks pointing outside.
+    pub fn resolve(&self, path: &Path) -> Result<PathBuf> {
+        if !self.lexically_allowed(path) {
+            return Err(ScopeDenied(path.to_path_buf()).into());
+        }
+
+        // Erst nach der Prüfung auf das Dateisystem zugreifen, sonst verrät 404/403 die Existenz
+        let canonical = std::fs::canonicalize(path)?;
+        if !self.contains(&canonical, true) {
+            return Err(ScopeDenied(path.to_path_buf()).into());
+        }
+        Ok(canonical)
+    }
+
+    /// Like [`Self::resolve`] for paths that may not exist yet, e.g. copy targets. The nearest
+    /// existing ancestor has to resolve into the scope; the path is returned as given.
+    /// A symlink as last component has to point into the scope as well, dangling ones are refused.
+    pub fn resolve_target(&self, path: &Path) -> Result<PathBuf> {
+        if !self.lexically_allowed(path) {
+            return Err(ScopeDenied(path.to_path_buf()).into());
+        }
+        // `exists()` folgt Symlinks, ein hängender Link gälte sonst als noch nicht vorhanden
+        if std::fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_symlink()) {
+            return match std::fs::canonicalize(path) {
+                Ok(target) if self.contains(&target, true) => Ok(path.to_path_buf()),
+                _ => Err(ScopeDenied(path.to_path_buf()).into()),
+            };
+        }
+        let existing = path
+            .ancestors()
+            .find(|ancestor| ancestor.exists())
+            .ok_or(ScopeDenied(path.to_path_buf()))?;
+        if !self.contains(&std::fs::canonicalize(existing)?, true) {
+            return Err(ScopeDenied(path.to_path_buf()).into());
+        }
+        Ok(path.to_path_buf())
+    }
+
+    /// Whether `path` is one of the allowed directories itself rather than something inside.
+    pub fn is_root(&self, path: &Path) -> bool {
+        let canonical = std::fs::canonicalize(path).ok();
+        self.allowed
+            .iter()
+            .any(|dir| dir == path || canonical.is_some() && std::fs::canonicalize(dir).ok() == canonical)
+    }
+
+    fn lexically_allowed(&self, path: &Path) -> bool {
+        path.is_absolute()
+            && !path.components().any(|c| matches!(c, Component::ParentDir))
+            && self.contains(path, false)
+    }
+
+    fn contains(&self, path: &Path, canonical: bool) -> bool {
+        self.allowed.iter().any(|dir| {
+            if canonical {
+                // Verzeichnisse, die (noch) nicht existieren, enthalten auch keine Dateien
+                std::fs::canonicalize(dir).is_ok_and(|dir| path.starts_with(dir))
+            } else {
+                path.starts_with(dir) || std::fs::canonicalize(dir).is_ok_and(|dir| path.starts_with(dir))
+            }
+        })
+    }
+}
+
+/// Named directories for `$PLACEHOLDER` scope entries and the `fs.*` API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+pub enum BaseDir {
+    #[serde(rename = "appData")]
+    AppData,
+    #[serde(rename = "appCache")]
+    AppCache,
+    #[serde(rename = "temp")]
+    Temp,
+    #[serde(rename = "home")]
+    Home,
+    #[serde(rename = "documents")]
+    Documents,
+    #[serde(rename = "downloads")]
+    Downloads,
+}
+
+impl BaseDir {
+    pub fn from_placeholder(placeholder: &str) -> Option<Self> {
+        match placeholder {
+            "$DATA" => Some(Self::AppData),
+            "$CACHE" => Some(Self::AppCache),
+            "$TEMP" => Some(Self::Temp),
+            "$HOME" => Some(Self::Home),
+            "$DOCUMENTS" => Some(Self::Documents),
+            "$DOWNLOADS" => Some(Self::Downloads),
+            _ => None,
+        }
+    }
+
+    pub fn path(self, launch_info: &LaunchInfo) -> Result<PathBuf> {
+        let user_dirs = || UserDirs::new().ok_or(anyhow!("Could not determine user directories"));
+        Ok(match self {
+            Self::AppData => launch_info.data_dir.clone(),
+            Self::AppCache => launch_info.cache_dir.clone(),
+            Self::Temp => launch_info.temp_dir.clone(),
+            Self::Home => user_dirs()?.home_dir().to_path_buf(),
+            Self::Documents => user_dirs()?
+                .document_dir()
+                .ok_or(anyhow!("No documents directory"))?
+                .to_path_buf(),
+            Self::Downloads => user_dirs()?
+                .download_dir()
+                .ok_or(anyhow!("No downloads directory"))?
+                .to_path_buf(),
+        })
+    }
+
+    /// Joins a relative `path` onto the directory. Absolute paths, `..` and symlinks
+    /// leading out of the directory fail with [`ScopeDenied`].
+    pub fn resolve(self, launch_info: &LaunchInfo, path: &str) -> Result<PathBuf> {
+        let base = self.path(launch_info)?;
+        let relative = Path::new(path);
+        let lexical_ok = relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+        if !lexical_ok {
+            return Err(ScopeDenied(relative.to_path_buf()).into());
+        }
+        let joined = base.join(relative);
+
+        // Der nächste existierende Vorfahre darf nicht per Symlink aus dem Verzeichnis führen
+        if let (Ok(base), Some(existing)) = (
+            std::fs::canonicalize(&base),
+            joined.ancestors().find(|ancestor| ancestor.exists()),
+        ) {
+            if !std::fs::canonicalize(existing)?.starts_with(&base) {
+                return Err(ScopeDenied(joined).into());
+            }
+        }
+        Ok(joined)
+    }
+}
+
+fn resolve_placeholder(entry: &str, launch_info: &LaunchInfo) -> Result<PathBuf> {
+    let (placeholder, rest) = match entry.split_once(['/', '\\']) {
+        Some((placeholder, rest)) => (placeholder, rest),
+        None => (entry, ""),
+    };
+    let base = match BaseDir::from_placeholder(placeholder) {
+        Some(base) => base.path(launch_info)?,
+        None if placeholder.starts_with('$') => return Err(anyhow!("Unknown placeholder {placeholder}")),
+        None => return Ok(PathBuf::from(entry)),
+    };
+    Ok(if rest.is_empty() { base } else { base.join(rest) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("pyframe-fs-scope-{name}-{}", std::process::id()));
+        fs::create_dir_all(root.join("scope")).unwrap();
+        fs::create_dir_all(root.join("secret")).unwrap();
+        fs::write(root.join("scope/a.txt"), "a").unwrap();
+        fs::write(root.join("secret/id_rsa"), "key").unwrap();
+        root
+    }
+
+    fn scope(root: &Path) -> FsScope {
+        let mut scope = FsScope::default();
+        scope.allow(root.join("scope"));
+        scope
+    }
+
+    fn launch_info(root: &Path) -> LaunchInfo {
+        LaunchInfo {
+            id_name: "test".to_string(),
+            data_dir: root.join("data"),
+            cache_dir: root.join("cache"),
+            temp_dir: root.join("temp"),
+            options: Default::default(),
+        }
+    }
+
+    fn is_denied(result: Result<PathBuf>) -> bool {
+        result.is_err_and(|err| err.is::<ScopeDenied>())
+    }
+
+    #[test]
+    fn parent_dir_traversal_is_denied() {
+        let root = temp_root("traversal");
+        let scope = scope(&root);
+
+        let escaped = root.join("scope/../secret/id_rsa");
+        assert!(is_denied(scope.resolve(&escaped)));
+        assert!(is_denied(scope.resolve_target(&escaped)));
+        // Auch wenn das Ziel wieder im Scope landet
+        assert!(is_denied(scope.resolve(&root.join("scope/../scope/a.txt"))));
+        assert!(is_denied(
+            BaseDir::AppData.resolve(&launch_info(&root), "../secret/id_rsa")
+        ));
+        assert_eq!(
+            scope.resolve(&root.join("scope/a.txt")).unwrap(),
+            fs::canonicalize(root.join("scope/a.txt")).unwrap()
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_out_of_the_scope_are_denied() {
+        let root = temp_root("symlink");
+        let scope = scope(&root);
+        std::os::unix::fs::symlink(root.join("secret"), root.join("scope/link")).unwrap();
+        std::os::unix::fs::symlink(root.join("secret/id_rsa"), root.join("scope/key")).unwrap();
+        std::os::unix::fs::symlink(root.join("secret/missing"), root.join("scope/dangling")).unwrap();
+        std::os::unix::fs::symlink(root.join("scope/a.txt"), root.join("scope/alias")).unwrap();
+
+        assert!(is_denied(scope.resolve(&root.join("scope/link/id_rsa"))));
+        assert!(is_denied(scope.resolve(&root.join("scope/key"))));
+        assert!(is_denied(scope.resolve_target(&root.join("scope/link/new.txt"))));
+        assert!(is_denied(scope.resolve_target(&root.join("scope/key"))));
+        assert!(is_denied(scope.resolve_target(&root.join("scope/dangling"))));
+        assert!(scope.resolve(&root.join("scope/alias")).is_ok());
+        assert!(scope.resolve_target(&root.join("scope/alias")).is_ok());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn placeholders_expand_to_the_launch_dirs() {
+        let root = temp_root("placeholder");
+        let launch_info = launch_info(&root);
+
+        assert_eq!(
+            resolve_placeholder("$DATA/x", &launch_info).unwrap(),
+            root.join("data").join("x")
+        );
+        assert_eq!(resolve_placeholder("$CACHE", &launch_info).unwrap(), root.join("cache"));
+        assert_eq!(
+            resolve_placeholder("$TEMP\\logs", &launch_info).unwrap(),
+            root.join("temp").join("logs")
+        );
+        assert!(resolve_placeholder("$UNKNOWN/x", &launch_info).is_err());
+        let absolute = root.join("scope").to_string_lossy().into_owned();
+        assert_eq!(
+            resolve_placeholder(&absolute, &launch_info).unwrap(),
+            root.join("scope")
+        );
+
+        let entries = ["$DATA/x".to_string(), "$NOPE".to_string(), "$DATA/x".to_string()];
+        let scope = FsScope::from_entries(&entries, &launch_info);
+        assert_eq!(scope.allowed(), [root.join("data").join("x")]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn targets_may_not_exist_yet() {
+        let root = temp_root("target");
+        let scope = scope(&root);
+
+        let target = root.join("scope/new/deeper.txt");
+        assert_eq!(scope.resolve_target(&target).unwrap(), target);
+        assert!(scope.resolve(&target).is_err_and(|err| !err.is::<ScopeDenied>()));
+        assert!(is_denied(scope.resolve_target(&root.join("secret/new.txt"))));
+        assert!(is_denied(scope.resolve_target(Path::new("scope/new.txt"))));
+        assert!(scope.is_root(&root.join("scope")));
+        assert!(!scope.is_root(&root.join("scope/a.txt")));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
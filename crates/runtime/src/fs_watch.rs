@@ -0,0 +1,189 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    log_err,
+    utils::{FrameEvent, UserEvent},
+    CoreApplication,
+};
+use anyhow::{anyhow, Result};
+use notify::{
+    event::{ModifyKind, RenameMode},
+    EventKind, RecommendedWatcher, RecursiveMode, Watcher,
+};
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc},
+    time::Duration,
+};
+
+const DEFAULT_DEBOUNCE_MS: u64 = 100;
+/// Event sent to the owning window (and Python) for every debounced batch.
+pub const WATCH_EVENT: &str = "fs.changed";
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    #[serde(rename = "create")]
+    Create,
+    #[serde(rename = "modify")]
+    Modify,
+    #[serde(rename = "remove")]
+    Remove,
+    /// `paths` holds the old and the new path.
+    #[serde(rename = "rename")]
+    Rename,
+}
+
+#[derive(Serialize, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// Relative to the base directory the watch was created with.
+    pub paths: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct WatchOptions {
+    pub recursive: bool,
+    /// Globs relative to the base directory; without any, every path matches.
+    pub include: Vec<glob::Pattern>,
+    pub exclude: Vec<glob::Pattern>,
+    pub debounce: Option<Duration>,
+}
+
+struct Watch {
+    window_id: u8,
+    /// Dropping the watcher closes the channel and ends the thread.
+    _watcher: RecommendedWatcher,
+}
+
+/// Watches started through `fs.watch`, owned by the window that started them.
+#[derive(Default)]
+pub struct FsWatchManager {
+    watches: HashMap<u32, Watch>,
+    next_id: u32,
+}
+
+impl FsWatchManager {
+    /// Watches `path` and emits [`WATCH_EVENT`] with `{id, changes}` to the window `window_id`.
+    /// Paths in the events are relative to `base`.
+    pub fn watch(
+        &mut self,
+        app: Arc<CoreApplication>,
+        window_id: u8,
+        base: PathBuf,
+        path: &Path,
+        options: WatchOptions,
+    ) -> Result<u32> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+            Ok(event) => {
+                let _ = tx.send(event);
+            }
+            Err(err) => log_err!(format!("File watcher error: {}", err)),
+        })?;
+        let mode = match options.recursive {
+            true => RecursiveMode::Recursive,
+            false => RecursiveMode::NonRecursive,
+        };
+        watcher.watch(path, mode)?;
+
+        self.next_id = self.next_id.wrapping_add(1);
+        let id = self.next_id;
+        let debounce = options.debounce.unwrap_or(Duration::from_millis(DEFAULT_DEBOUNCE_MS));
+        // Manche Backends melden kanonische Pfade, andere den beobachteten
+        let roots = vec![base.canonicalize().unwrap_or(base.clone()), base];
+
+        std::thread::spawn(move || {
+            while let Ok(event) = rx.recv() {
+                let mut changes = Vec::new();
+                collect(&roots, &options, event, &mut changes);
+                loop {
+                    match rx.recv_timeout(debounce) {
+                        Ok(event) => collect(&roots, &options, event, &mut changes),
+                        Err(mpsc::RecvTimeoutError::Timeout) => break,
+                        Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+                if changes.is_empty() {
+                    continue;
+                }
+                let payload = serde_json::json!({ "id": id, "changes": changes });
+                // Das Fenster nur im Event-Loop anfassen, sonst könnte es hier zuletzt freigegeben werden
+                let event_app = app.clone();
+                let emit = FrameEvent::new(move |_, _| {
+                    if let Ok(window) = event_app.window()?.get_window(window_id) {
+                        window.emit_event(WATCH_EVENT, &payload)?;
+                    }
+                    Ok(())
+                });
+                if app.proxy.send_event(UserEvent::FrameEvent(emit)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        self.watches.insert(
+            id,
+            Watch {
+                window_id,
+                _watcher: watcher,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Stops a watch; only the window that started it may stop it.
+    pub fn unwatch(&mut self, window_id: u8, id: u32) -> Result<()> {
+        match self.watches.get(&id) {
+            Some(watch) if watch.window_id == window_id => {
+                self.watches.remove(&id);
+                Ok(())
+            }
+            _ => Err(anyhow!("Watch with id {} not found", id)),
+        }
+    }
+
+    pub fn unwatch_all(&mut self, window_id: u8) {
+        self.watches.retain(|_, watch| watch.window_id != window_id);
+    }
+}
+
+fn kind_of(kind: &EventKind) -> Option<ChangeKind> {
+    Some(match kind {
+        EventKind::Create(_) => ChangeKind::Create,
+        EventKind::Remove(_) => ChangeKind::Remove,
+        // Halbe Umbenennungen (nur Quelle oder nur Ziel im beobachteten Bereich)
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => ChangeKind::Remove,
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => ChangeKind::Create,
+        EventKind::Modify(ModifyKind::Name(_)) => ChangeKind::Rename,
+        EventKind::Modify(_) | EventKind::Any => ChangeKind::Modify,
+        EventKind::Access(_) | EventKind::Other => return None,
+    })
+}
+
+fn collect(roots: &[PathBuf], options: &WatchOptions, event: notify::Event, changes: &mut Vec<Change>) {
+    let Some(kind) = kind_of(&event.kind) else {
+        return;
+    };
+    let paths: Vec<String> = event
+        .paths
+        .iter()
+        .filter_map(|path| roots.iter().find_map(|root| path.strip_prefix(root).ok()))
+        .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+        .collect();
+    let matches = |path: &String| {
+        (options.include.is_empty() || options.include.iter().any(|pattern| pattern.matches(path)))
+            && !options.exclude.iter().any(|pattern| pattern.matches(path))
+    };
+    if paths.is_empty() || !paths.iter().any(matches) {
+        return;
+    }
+    let change = Change { kind, paths };
+    // Editoren schreiben oft mehrmals hintereinander, gleiche Änderungen nur einmal melden
+    if !changes.contains(&change) {
+        changes.push(change);
+    }
+}
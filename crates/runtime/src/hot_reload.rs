@@ -0,0 +1,125 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    log_err,
+    options::HotReloadOptions,
+    utils::{get_host_from_url, make_base_url, FrameEventLoopProxy, UserEvent},
+    CoreApplication,
+};
+use anyhow::Result;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::{
+    collections::BTreeSet,
+    path::PathBuf,
+    sync::{mpsc, Arc},
+    time::Duration,
+};
+
+const DEFAULT_DEBOUNCE_MS: u64 = 150;
+const DEFAULT_IGNORE: &[&str] = &[
+    "**/.*",
+    "**/.git/**",
+    "**/node_modules/**",
+    "**/__pycache__/**",
+    "**/*~",
+];
+
+/// Watches `root` in a background thread and sends debounced [`UserEvent::ResourcesChanged`]
+/// with paths relative to `root`. Only started for `debug_resource`.
+pub fn watch(root: PathBuf, options: HotReloadOptions, proxy: FrameEventLoopProxy) -> Result<()> {
+    let ignore = options
+        .ignore
+        .unwrap_or_else(|| DEFAULT_IGNORE.iter().map(|pattern| pattern.to_string()).collect())
+        .iter()
+        .filter_map(|pattern| match glob::Pattern::new(pattern) {
+            Ok(pattern) => Some(pattern),
+            Err(err) => {
+                log_err!(format!("Invalid hot reload ignore pattern '{}': {}", pattern, err));
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    let debounce = Duration::from_millis(options.debounce_ms.unwrap_or(DEFAULT_DEBOUNCE_MS));
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+        Ok(event) if !matches!(event.kind, EventKind::Access(_)) => {
+            let _ = tx.send(event.paths);
+        }
+        Ok(_) => {}
+        Err(err) => log_err!(format!("Hot reload watcher error: {}", err)),
+    })?;
+    watcher.watch(&root, RecursiveMode::Recursive)?;
+    // Manche Backends melden kanonische Pfade, andere den beobachteten
+    let roots = vec![root.canonicalize().unwrap_or(root.clone()), root];
+
+    std::thread::spawn(move || {
+        // Der Watcher lebt so lange wie dieser Thread
+        let _watcher = watcher;
+        while let Ok(paths) = rx.recv() {
+            let mut changed = BTreeSet::new();
+            collect(&roots, &ignore, paths, &mut changed);
+            // Weitere Events sammeln, bis `debounce` lang nichts mehr kommt
+            loop {
+                match rx.recv_timeout(debounce) {
+                    Ok(paths) => collect(&roots, &ignore, paths, &mut changed),
+                    Err(mpsc::RecvTimeoutError::Timeout) => break,
+                    Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                }
+            }
+            if !changed.is_empty()
+                && proxy
+                    .send_event(UserEvent::ResourcesChanged(changed.into_iter().collect()))
+                    .is_err()
+            {
+                return;
+            }
+        }
+    });
+    Ok(())
+}
+
+fn collect(roots: &[PathBuf], ignore: &[glob::Pattern], paths: Vec<PathBuf>, changed: &mut BTreeSet<String>) {
+    for path in paths {
+        let Some(relative) = roots.iter().find_map(|root| path.strip_prefix(root).ok()) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if !ignore.iter().any(|pattern| pattern.matches(&relative)) {
+            changed.insert(relative);
+        }
+    }
+}
+
+impl CoreApplication {
+    /// Swaps changed stylesheets in place, everything else reloads the windows served from `pyframe://`
+    /// or from the origin of `debug_entry`.
+    pub fn reload_resources(self: &Arc<Self>, paths: Vec<String>) -> Result<()> {
+        self.i18n()?.invalidate(&paths);
+        let base_url = make_base_url("pyframe", &self.launch_info.id_name);
+        let debug_origin = self
+            .launch_info
+            .options
+            .debug_entry
+            .as_deref()
+            .and_then(get_host_from_url);
+        let css_only = paths.iter().all(|path| path.ends_with(".css"));
+        let manager = self.window()?;
+        for window in manager.list_windows() {
+            let served = window.webview.url().is_ok_and(|url| {
+                url.starts_with(&base_url) || (debug_origin.is_some() && get_host_from_url(&url) == debug_origin)
+            });
+            if !served {
+                continue;
+            }
+            if css_only {
+                window.send_ipc_event("resources.cssChanged", &paths)?;
+            } else {
+                window.webview.reload()?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,25 +2,26 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use image::ImageFormat;
 use muda;
 use muda::accelerator::Code as MudaCode;
 use muda::accelerator::Modifiers as MudaModifiers;
 use muda::NativeIcon;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::{fs::read, path::PathBuf};
+use std::{fs::read, path::Path};
 use tao::dpi::PhysicalSize;
 use tao::window::CursorIcon;
 use tao::window::ResizeDirection;
 use tao::window::Window;
 
+use crate::log_err;
 use crate::options::menu::CheckMenuItem;
 use crate::options::menu::IconMenuItem;
 use crate::options::menu::MenuItem;
 use crate::options::menu::PredefinedMenuItem;
 use crate::options::menu::Submenu;
 use crate::options::menu::SystemTray;
+use crate::resource_manager::{image_utils, ResourceManager};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum AcceleratorCode {
@@ -943,39 +944,34 @@ pub fn create_muda_accelerator(
 
     muda::accelerator::Accelerator::new(Some(modifiers), key.into())
 }
-pub fn muda_menu_icon(icon_path: PathBuf) -> Option<muda::Icon> {
-    let icon_object = match read(&icon_path) {
-        Ok(bytes) => match image::load_from_memory_with_format(&bytes, ImageFormat::Png) {
-            Ok(loaded) => {
-                let image_buffer = loaded.to_rgba8();
-                let (icon_width, icon_height) = image_buffer.dimensions();
-                let icon_rgba = image_buffer.into_raw();
+/// Icons are looked up in the app resources; absolute paths outside of them are read from disk.
+fn icon_data(resource: &dyn ResourceManager, icon_path: &Path) -> anyhow::Result<(Vec<u8>, String)> {
+    let name = icon_path.to_string_lossy().replace('\\', "/");
+    let name = name.trim_start_matches("./");
+    if resource.exists(name) {
+        return Ok((resource.load(name)?, name.to_string()));
+    }
+    if icon_path.is_absolute() {
+        return Ok((read(icon_path)?, name.to_string()));
+    }
+    Err(anyhow::anyhow!("Icon {} not found.", name))
+}
 
-                match muda::Icon::from_rgba(icon_rgba, icon_width, icon_height) {
-                    Ok(icon) => Some(icon),
-                    Err(_) => {
-                        println!("Failed to create icon from RGBA data.");
-                        None
-                    }
-                }
-            }
-            Err(_) => {
-                println!("Failed to load the image from the specified icon path.");
-                None
-            }
-        },
-        Err(_) => {
-            println!("Failed to read the icon file from path: {:?}", icon_path);
+pub fn muda_menu_icon(resource: &dyn ResourceManager, icon_path: &Path, scale_factor: f64) -> Option<muda::Icon> {
+    match icon_data(resource, icon_path).and_then(|(data, name)| image_utils::menu_icon(&data, &name, scale_factor)) {
+        Ok(icon) => Some(icon),
+        Err(err) => {
+            log_err!(format!("Failed to load menu icon {:?}: {}", icon_path, err));
             None
         }
-    };
-
-    icon_object
+    }
 }
 
 #[allow(dead_code)]
 pub fn create_muda_about_metadata_menu_item(
+    resource: &dyn ResourceManager,
     metadata: Option<crate::options::menu::AboutMetadata>,
+    scale_factor: f64,
 ) -> muda::AboutMetadata {
     let mut about_metadata = muda::AboutMetadata::default();
 
@@ -993,36 +989,28 @@ pub fn create_muda_about_metadata_menu_item(
 
         // Set the icon if it exists and is provided in the metadata
         if let Some(icon) = metadata.icon.clone() {
-            about_metadata.icon = muda_menu_icon(icon);
+            about_metadata.icon = muda_menu_icon(resource, &icon, scale_factor);
         }
     }
 
     about_metadata
 }
-#[allow(dead_code)]
-pub fn system_tray_icon(icon_path: PathBuf) -> tray_icon::Icon {
-    // Icon-Datei lesen
-    let bytes = read(&icon_path).unwrap_or_else(|_| panic!("Failed to read the icon file from path: {:?}", icon_path));
-
-    // Bild laden
-    let loaded = image::load_from_memory_with_format(&bytes, ImageFormat::Png)
-        .unwrap_or_else(|_| panic!("Failed to load the image from the specified icon path."));
-
-    // In RGBA konvertieren
-    let image_buffer = loaded.to_rgba8();
-    let (icon_width, icon_height) = image_buffer.dimensions();
-    let icon_rgba = image_buffer.into_raw();
-
-    // Icon erstellen
-    tray_icon::Icon::from_rgba(icon_rgba, icon_width, icon_height)
-        .unwrap_or_else(|_| panic!("Failed to create icon from RGBA data."))
+pub fn system_tray_icon(
+    resource: &dyn ResourceManager,
+    icon_path: &Path,
+    scale_factor: f64,
+) -> anyhow::Result<tray_icon::Icon> {
+    let (data, name) = icon_data(resource, icon_path)?;
+    image_utils::tray_icon(&data, &name, scale_factor)
 }
 
 #[allow(dead_code)]
 pub fn create_predefined_menu_item(
+    resource: &dyn ResourceManager,
     item: PredefinedMenuItem,
+    scale_factor: f64,
 ) -> (muda::MenuId, muda::PredefinedMenuItem, Option<String>) {
-    let predefind_metadata = create_muda_about_metadata_menu_item(item.metadata);
+    let predefind_metadata = create_muda_about_metadata_menu_item(resource, item.metadata, scale_factor);
     let predefined_item = match item.item_type.as_str() {
         "separator" => muda::PredefinedMenuItem::separator(),
         "about" => muda::PredefinedMenuItem::about(None, Some(predefind_metadata)),
@@ -1060,8 +1048,12 @@ pub fn create_check_menuitem(item: CheckMenuItem) -> (muda::MenuId, muda::CheckM
     (check_item_id, check_item, py_function)
 }
 
-pub fn create_icon_menuitem(item: IconMenuItem) -> (muda::MenuId, muda::IconMenuItem, Option<String>) {
-    let menu_icon = muda_menu_icon(item.icon_path.clone());
+pub fn create_icon_menuitem(
+    resource: &dyn ResourceManager,
+    item: IconMenuItem,
+    scale_factor: f64,
+) -> (muda::MenuId, muda::IconMenuItem, Option<String>) {
+    let menu_icon = muda_menu_icon(resource, &item.icon_path, scale_factor);
     let accelerator = create_muda_accelerator(Some(item.modifier.clone()), item.key.clone());
     let icon_item = muda::IconMenuItem::new(item.text.clone(), item.enabled, menu_icon, Some(accelerator));
     let py_function = item.command_id.clone();
@@ -1079,8 +1071,10 @@ pub fn create_menuitem(item: MenuItem) -> (muda::MenuId, muda::MenuItem, Option<
 
 /// Erzeugt ein Submenü und fügt es in die übergebene Map ein.
 pub fn create_submenu(
+    resource: &dyn ResourceManager,
     item: Submenu,
     menu_api: &mut HashMap<muda::MenuId, (muda::MenuItemKind, Option<String>)>,
+    scale_factor: f64,
 ) -> muda::Submenu {
     let submenu = muda::Submenu::new(item.text.clone(), item.enabled);
 
@@ -1094,7 +1088,7 @@ pub fn create_submenu(
 
     if let Some(icon_menus) = item.icon_menu {
         for icon_menu in icon_menus {
-            let (menu_id, menu_item, py_command) = create_icon_menuitem(icon_menu.clone());
+            let (menu_id, menu_item, py_command) = create_icon_menuitem(resource, icon_menu.clone(), scale_factor);
             submenu.append(&menu_item).ok();
             menu_api.insert(menu_id, (muda::MenuItemKind::Icon(menu_item), py_command));
         }
@@ -1110,7 +1104,8 @@ pub fn create_submenu(
 
     if let Some(predefined_menu) = &item.predefined_menu {
         for predefined_menu in predefined_menu {
-            let (menu_id, menu_item, py_command) = create_predefined_menu_item(predefined_menu.clone());
+            let (menu_id, menu_item, py_command) =
+                create_predefined_menu_item(resource, predefined_menu.clone(), scale_factor);
             submenu.append(&menu_item).ok();
             menu_api.insert(menu_id, (muda::MenuItemKind::Predefined(menu_item), py_command));
         }
@@ -1119,10 +1114,15 @@ pub fn create_submenu(
     submenu
 }
 
-pub fn init_sys_tray(tra_options: SystemTray, window_menu: muda::Menu) -> anyhow::Result<tray_icon::TrayIcon> {
+pub fn init_sys_tray(
+    resource: &dyn ResourceManager,
+    tra_options: SystemTray,
+    window_menu: muda::Menu,
+    scale_factor: f64,
+) -> anyhow::Result<tray_icon::TrayIcon> {
     let mut builder = tray_icon::TrayIconBuilder::new();
     if let Some(icon_path) = &tra_options.icon {
-        let icon = crate::hylper::system_tray_icon(icon_path.into());
+        let icon = system_tray_icon(resource, Path::new(icon_path), scale_factor)?;
         builder = builder.with_icon(icon);
     }
     #[cfg(not(target_os = "windows"))]
@@ -1193,9 +1193,10 @@ impl HitTestResult {
     }
 }
 
-pub fn hit_test(window_size: PhysicalSize<u32>, x: i32, y: i32, scale: f64) -> HitTestResult {
-    const BORDERLESS_RESIZE_INSET: f64 = 5.0;
+/// Default width of the resize border of undecorated windows, in logical pixels.
+pub const BORDERLESS_RESIZE_INSET: f64 = 5.0;
 
+pub fn hit_test(window_size: PhysicalSize<u32>, x: i32, y: i32, scale: f64, inset: f64) -> HitTestResult {
     const CLIENT: isize = 0b0000;
     const LEFT: isize = 0b0001;
     const RIGHT: isize = 0b0010;
@@ -1211,7 +1212,7 @@ pub fn hit_test(window_size: PhysicalSize<u32>, x: i32, y: i32, scale: f64) -> H
     let bottom = top + window_size.height as i32;
     let right = left + window_size.width as i32;
 
-    let inset = (BORDERLESS_RESIZE_INSET * scale) as i32;
+    let inset = (inset * scale) as i32;
 
     #[rustfmt::skip]
         let result =
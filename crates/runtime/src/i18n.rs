@@ -0,0 +1,387 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{log_err, options::I18nOptions, resource_manager::ResourceManager, utils::UserEvent, CoreApplication};
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+use std::{collections::HashMap, sync::Arc};
+
+const DEFAULT_DIR: &str = "locales";
+const DEFAULT_FALLBACK: &str = "en";
+/// Menu and tray labels starting with this prefix are looked up in the catalogs, e.g. `i18n:menu.quit`.
+pub const LABEL_PREFIX: &str = "i18n:";
+pub const LOCALE_CHANGED_EVENT: &str = "locale.changed";
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+enum Message {
+    Text(String),
+    /// CLDR category (`one`, `few`, ...) to text; always has `other`.
+    Plural(HashMap<String, String>),
+}
+
+/// Flattened `<dir>/<locale>.json`: nested objects become dotted keys, objects whose keys are
+/// all plural categories (and include `other`) are plural messages.
+struct Catalog {
+    messages: HashMap<String, Message>,
+}
+
+impl Catalog {
+    fn parse(data: &[u8]) -> Result<Self> {
+        let root: Map<String, Value> = serde_json::from_slice(data)?;
+        let mut messages = HashMap::new();
+        flatten("", &root, &mut messages);
+        Ok(Self { messages })
+    }
+}
+
+fn flatten(prefix: &str, object: &Map<String, Value>, messages: &mut HashMap<String, Message>) {
+    for (key, value) in object {
+        let key = match prefix.is_empty() {
+            true => key.clone(),
+            false => format!("{}.{}", prefix, key),
+        };
+        match value {
+            Value::String(text) => {
+                messages.insert(key, Message::Text(text.clone()));
+            }
+            Value::Object(object) if is_plural(object) => {
+                let forms = object
+                    .iter()
+                    .filter_map(|(category, text)| Some((category.clone(), text.as_str()?.to_string())))
+                    .collect();
+                messages.insert(key, Message::Plural(forms));
+            }
+            Value::Object(object) => flatten(&key, object, messages),
+            _ => {}
+        }
+    }
+}
+
+fn is_plural(object: &Map<String, Value>) -> bool {
+    object.contains_key("other")
+        && object
+            .iter()
+            .all(|(key, value)| PLURAL_CATEGORIES.contains(&key.as_str()) && value.is_string())
+}
+
+/// Translation catalogs from the app resources with locale fallback chains, `{name}` interpolation
+/// and CLDR plural rules. Catalogs are loaded on first use and cached.
+pub struct I18n {
+    resource: Arc<dyn ResourceManager>,
+    dir: String,
+    fallback: String,
+    locale: String,
+    /// `None` for locales without a catalog, so they are not looked up again.
+    catalogs: HashMap<String, Option<Arc<Catalog>>>,
+}
+
+impl std::fmt::Debug for I18n {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("I18n")
+            .field("dir", &self.dir)
+            .field("locale", &self.locale)
+            .finish()
+    }
+}
+
+impl I18n {
+    pub fn new(options: Option<&I18nOptions>, resource: Arc<dyn ResourceManager>) -> Self {
+        let options = options.cloned().unwrap_or_default();
+        let locale = options.locale.unwrap_or_else(system_locale);
+        Self {
+            resource,
+            dir: options
+                .dir
+                .map(|dir| dir.trim_matches('/').to_string())
+                .unwrap_or_else(|| DEFAULT_DIR.to_string()),
+            fallback: normalize(&options.fallback_locale.unwrap_or_else(|| DEFAULT_FALLBACK.to_string())),
+            locale: normalize(&locale),
+            catalogs: HashMap::new(),
+        }
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// `None` goes back to the system locale. Returns whether the locale changed.
+    pub fn set_locale(&mut self, locale: Option<&str>) -> bool {
+        let locale = normalize(&locale.map(str::to_string).unwrap_or_else(system_locale));
+        if locale == self.locale {
+            return false;
+        }
+        self.locale = locale;
+        true
+    }
+
+    /// Locales tried in order, e.g. `de-AT`, `de`, `en`.
+    pub fn chain(&self) -> Vec<String> {
+        fallback_chain(&self.locale, &self.fallback)
+    }
+
+    /// Locales with a catalog in the resources.
+    pub fn available(&self) -> Result<Vec<String>> {
+        let prefix = format!("{}/", self.dir);
+        Ok(self
+            .resource
+            .list()?
+            .iter()
+            .filter_map(|path| path.strip_prefix(&prefix)?.strip_suffix(".json"))
+            .filter(|name| !name.contains('/'))
+            .map(normalize)
+            .collect())
+    }
+
+    /// Drops cached catalogs that are among the changed resource `paths`.
+    pub fn invalidate(&mut self, paths: &[String]) {
+        let prefix = format!("{}/", self.dir);
+        if paths.iter().any(|path| path.starts_with(&prefix)) {
+            self.catalogs.clear();
+        }
+    }
+
+    /// Translates `key`; `count` selects the plural form and is available as `{count}`.
+    /// Unknown keys are returned unchanged.
+    pub fn translate(&mut self, key: &str, args: Option<&Map<String, Value>>, count: Option<i64>) -> String {
+        for locale in self.chain() {
+            let Some(catalog) = self.catalog(&locale) else {
+                continue;
+            };
+            let text = match catalog.messages.get(key) {
+                Some(Message::Text(text)) => text,
+                Some(Message::Plural(forms)) => {
+                    let category = plural_category(&locale, count.unwrap_or(0));
+                    match forms.get(category).or_else(|| forms.get("other")) {
+                        Some(text) => text,
+                        None => continue,
+                    }
+                }
+                None => continue,
+            };
+            return interpolate(text, args, count);
+        }
+        key.to_string()
+    }
+
+    /// Resolves `i18n:<key>` labels, other labels are returned as they are.
+    pub fn label(&mut self, text: &str) -> String {
+        match text.strip_prefix(LABEL_PREFIX) {
+            Some(key) => self.translate(key, None, None),
+            None => text.to_string(),
+        }
+    }
+
+    /// All messages of the current chain, the most specific locale wins. Plural messages are
+    /// returned as objects, for libraries in the page that do their own formatting.
+    pub fn messages(&mut self) -> Map<String, Value> {
+        let mut merged = Map::new();
+        for locale in self.chain().iter().rev() {
+            let Some(catalog) = self.catalog(locale) else {
+                continue;
+            };
+            for (key, message) in &catalog.messages {
+                let value = match message {
+                    Message::Text(text) => Value::String(text.clone()),
+                    Message::Plural(forms) => serde_json::json!(forms),
+                };
+                merged.insert(key.clone(), value);
+            }
+        }
+        merged
+    }
+
+    fn catalog(&mut self, locale: &str) -> Option<Arc<Catalog>> {
+        if let Some(catalog) = self.catalogs.get(locale) {
+            return catalog.clone();
+        }
+        let path = format!("{}/{}.json", self.dir, locale);
+        let catalog = match self.resource.exists(&path) {
+            true => match self.resource.load(&path).and_then(|data| Catalog::parse(&data)) {
+                Ok(catalog) => Some(Arc::new(catalog)),
+                Err(err) => {
+                    log_err!(format!("Invalid catalog {}: {}", path, err));
+                    None
+                }
+            },
+            false => None,
+        };
+        self.catalogs.insert(locale.to_string(), catalog.clone());
+        catalog
+    }
+}
+
+impl CoreApplication {
+    /// Switches the locale; the event loop then retranslates menus and tray and emits `locale.changed`.
+    pub fn set_locale(&self, locale: Option<&str>) -> Result<String> {
+        let mut i18n = self.i18n()?;
+        if i18n.set_locale(locale) {
+            self.proxy
+                .send_event(UserEvent::LocaleChanged)
+                .map_err(|_| anyhow!("Failed to send event"))?;
+        }
+        Ok(i18n.locale().to_string())
+    }
+}
+
+fn system_locale() -> String {
+    sys_locale::get_locale().unwrap_or_else(|| DEFAULT_FALLBACK.to_string())
+}
+
+/// `de_AT.UTF-8` → `de-AT`; language lowercase, region uppercase.
+fn normalize(locale: &str) -> String {
+    let locale = locale.split(['.', '@']).next().unwrap_or(locale).replace('_', "-");
+    locale
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .enumerate()
+        .map(|(index, part)| match (index, part.len()) {
+            (0, _) => part.to_lowercase(),
+            (_, 2) => part.to_uppercase(),
+            // Schrift-Subtags wie `Hant`
+            (_, 4) if part.is_ascii() => part[..1].to_uppercase() + &part[1..].to_lowercase(),
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// `locale` and its parents, followed by `fallback` and its parents: `de-AT`, `de`, `en`.
+fn fallback_chain(locale: &str, fallback: &str) -> Vec<String> {
+    let mut chain: Vec<String> = Vec::new();
+    for mut current in [locale, fallback] {
+        loop {
+            if !current.is_empty() && !chain.iter().any(|locale| locale == current) {
+                chain.push(current.to_string());
+            }
+            match current.rsplit_once('-') {
+                Some((parent, _)) => current = parent,
+                None => break,
+            }
+        }
+    }
+    chain
+}
+
+fn interpolate(text: &str, args: Option<&Map<String, Value>>, count: Option<i64>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let name = &rest[start + 1..start + end];
+        let value = match (args.and_then(|args| args.get(name)), name) {
+            (Some(Value::String(value)), _) => Some(value.clone()),
+            (Some(value), _) => Some(value.to_string()),
+            (None, "count") => count.map(|count| count.to_string()),
+            (None, _) => None,
+        };
+        match value {
+            Some(value) => result.push_str(&value),
+            // Unbekannte Platzhalter bleiben stehen, damit sie auffallen
+            None => result.push_str(&rest[start..=start + end]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// CLDR cardinal plural category for integer counts.
+fn plural_category(locale: &str, count: i64) -> &'static str {
+    let language = locale.split('-').next().unwrap_or(locale);
+    let n = count.unsigned_abs();
+    let (n10, n100) = (n % 10, n % 100);
+    match language {
+        "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" => "other",
+        // Hier zählt auch 0 als Singular
+        "fr" | "hy" | "kab" => match n {
+            0 | 1 => "one",
+            _ => "other",
+        },
+        "pt" if locale != "pt-PT" => match n {
+            0 | 1 => "one",
+            _ => "other",
+        },
+        "ru" | "uk" | "be" => match (n10, n100) {
+            (1, _) if n100 != 11 => "one",
+            (2..=4, _) if !(12..=14).contains(&n100) => "few",
+            _ => "many",
+        },
+        "pl" => match (n, n10, n100) {
+            (1, _, _) => "one",
+            (_, 2..=4, _) if !(12..=14).contains(&n100) => "few",
+            _ => "many",
+        },
+        "cs" | "sk" => match n {
+            1 => "one",
+            2..=4 => "few",
+            _ => "other",
+        },
+        "ar" => match (n, n100) {
+            (0, _) => "zero",
+            (1, _) => "one",
+            (2, _) => "two",
+            (_, 3..=10) => "few",
+            (_, 11..=99) => "many",
+            _ => "other",
+        },
+        _ if n == 1 => "one",
+        _ => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn russian_plurals_follow_the_last_digits() {
+        assert_eq!(plural_category("ru", 1), "one");
+        assert_eq!(plural_category("ru", 2), "few");
+        assert_eq!(plural_category("ru", 5), "many");
+        assert_eq!(plural_category("ru", 11), "many");
+        assert_eq!(plural_category("ru", 21), "one");
+    }
+
+    #[test]
+    fn polish_teens_are_many() {
+        assert_eq!(plural_category("pl", 22), "few");
+        assert_eq!(plural_category("pl", 12), "many");
+    }
+
+    #[test]
+    fn arabic_has_all_six_categories() {
+        assert_eq!(plural_category("ar", 0), "zero");
+        assert_eq!(plural_category("ar", 3), "few");
+        assert_eq!(plural_category("ar", 11), "many");
+        assert_eq!(plural_category("ar", 100), "other");
+    }
+
+    #[test]
+    fn posix_locales_are_normalized() {
+        assert_eq!(normalize("de_AT.UTF-8"), "de-AT");
+        assert_eq!(normalize("zh_hant_tw"), "zh-Hant-TW");
+    }
+
+    #[test]
+    fn chain_walks_up_to_the_fallback() {
+        assert_eq!(fallback_chain(&normalize("de_AT.UTF-8"), "en"), ["de-AT", "de", "en"]);
+        // Die Fallback-Sprache kommt nicht doppelt vor
+        assert_eq!(fallback_chain("en-GB", "en"), ["en-GB", "en"]);
+    }
+
+    #[test]
+    fn unknown_placeholders_stay_in_place() {
+        let args = serde_json::json!({ "name": "Ada" });
+        let args = args.as_object();
+        assert_eq!(
+            interpolate("Hallo {name}, {missing}!", args, None),
+            "Hallo Ada, {missing}!"
+        );
+        assert_eq!(interpolate("{count} Dateien", None, Some(3)), "3 Dateien");
+        assert_eq!(interpolate("{count} Dateien", None, None), "{count} Dateien");
+    }
+}
@@ -5,33 +5,55 @@ pub mod api;
 pub mod api_manager;
 pub mod assets;
 pub mod event_handler;
+pub mod file_guard;
+pub mod fs_scope;
+pub mod fs_watch;
+pub mod hot_reload;
 pub mod hylper;
+pub mod i18n;
+pub mod lifecycle;
 pub mod menu_manager;
 pub mod options;
 pub mod resource_manager;
 pub mod shortcut_manager;
+pub mod splash;
 pub mod tray_manager;
 pub mod utils;
 pub mod window_manager;
 
 use api::register_api_instances;
-use api_manager::ApiManager;
+use api_manager::{ApiManager, ThreadPool};
 use event_handler::EventHandler;
 use menu_manager::PyFrameMenuManager;
-use resource_manager::{AppResourceManager, FileSystemResource, ResourceManager};
-use std::sync::{Arc, MutexGuard};
-use utils::{ArcMut, FrameEventLoopProxy};
+use resource_manager::{
+    layered::{self, LayeredResource},
+    zip_resource::ZipResource,
+    FileSystemResource, ResourceManager,
+};
+use splash::SplashScreen;
+use std::{
+    sync::{atomic::Ordering, Arc, MutexGuard},
+    time::{Duration, Instant},
+};
+use utils::{ArcMut, FrameEventLoopProxy, UserEvent};
 use window_manager::WindowManager;
 
 pub struct CoreApplication {
-    _resource: Arc<dyn ResourceManager>,
+    _resource: Arc<LayeredResource>,
     _window_manager: ArcMut<WindowManager>,
     _api_manager: ArcMut<ApiManager>,
+    _thread_pool: ArcMut<ThreadPool>,
     pub launch_info: options::LaunchInfo,
     _shortcut: ArcMut<shortcut_manager::PyFrameShortcutManager>,
     proxy: FrameEventLoopProxy,
     _menu_bar: ArcMut<PyFrameMenuManager>,
     _init_tray: ArcMut<Option<options::MenuMode>>,
+    _socket_settings: ArcMut<Option<options::SocketSettings>>,
+    _lifecycle: ArcMut<lifecycle::Lifecycle>,
+    _fs_scope: ArcMut<fs_scope::FsScope>,
+    _file_guard: Arc<file_guard::FileGuard>,
+    _fs_watch: ArcMut<fs_watch::FsWatchManager>,
+    _i18n: ArcMut<i18n::I18n>,
 }
 
 impl CoreApplication {
@@ -43,10 +65,23 @@ impl CoreApplication {
         let proxy = event_loop.create_proxy();
         let launch_info = options::LaunchInfo::new(data)?;
 
-        let resource_manager: Arc<dyn ResourceManager> = match &launch_info.options.debug_resource {
-            Some(dir) => FileSystemResource::new(dir)?,
-            None => AppResourceManager::new()?,
-        };
+        let app_options = &launch_info.options;
+        let resource_manager: Arc<dyn ResourceManager> =
+            match (&app_options.debug_resource, &app_options.resource_archive) {
+                (Some(dir), _) => FileSystemResource::new(dir)?,
+                (None, Some(archive)) => ZipResource::new(archive)?,
+                (None, None) => resource_manager::load_app_bundle(app_options.bundle.as_ref())?,
+            };
+        // Overrides würden die Signaturprüfung des Bundles aushebeln
+        let signed = app_options
+            .bundle
+            .as_ref()
+            .is_some_and(|bundle| bundle.public_key.is_some());
+        let resource_manager = LayeredResource::new(resource_manager, signed);
+        let overrides_dir = launch_info.data_dir.join(layered::OVERRIDES_DIR);
+        if !signed && overrides_dir.is_dir() {
+            resource_manager.mount(layered::OVERRIDES_LAYER, None, FileSystemResource::new(&overrides_dir)?)?;
+        }
 
         #[cfg(target_os = "macos")]
         if let Some(macos_extra) = &launch_info.options.macos_extra {
@@ -73,24 +108,34 @@ impl CoreApplication {
 
         // create api manager and register api instances
         let api_manager = ApiManager::new(&launch_info.options);
-        {
+        let thread_pool = {
             let mut api_manager = lock!(api_manager)?;
             register_api_instances(&mut api_manager);
-        }
+            api_manager.thread_pool()
+        };
         let menu_manager = PyFrameMenuManager::new(_menu_bar);
         let window_manager = WindowManager::new(Some(launch_info.data_dir.clone()));
 
         let shortcut_manager = shortcut_manager::PyFrameShortcutManager::new(event_loop);
 
+        let i18n = i18n::I18n::new(launch_info.options.i18n.as_ref(), resource_manager.clone());
+
         let app = Self {
             _resource: resource_manager,
             _window_manager: window_manager.clone(),
             _api_manager: api_manager.clone(),
+            _thread_pool: thread_pool,
             launch_info: launch_info.clone(),
             _shortcut: shortcut_manager.clone(),
             proxy,
             _menu_bar: menu_manager.clone(),
             _init_tray: utils::arc_mut(launch_info.options.menu_mode),
+            _socket_settings: utils::arc_mut(None),
+            _lifecycle: utils::arc_mut(lifecycle::Lifecycle::default()),
+            _fs_scope: utils::arc_mut(fs_scope::FsScope::new(&launch_info)),
+            _file_guard: utils::arc(file_guard::FileGuard::new(&launch_info)),
+            _fs_watch: utils::arc_mut(fs_watch::FsWatchManager::default()),
+            _i18n: utils::arc_mut(i18n),
         };
 
         let application = std::sync::Arc::new(app);
@@ -106,6 +151,11 @@ impl CoreApplication {
         self._resource.clone()
     }
 
+    /// The resource stack for mounting and unmounting layers at runtime.
+    pub fn resource_layers(self: &Arc<Self>) -> Arc<LayeredResource> {
+        self._resource.clone()
+    }
+
     pub fn window(&self) -> anyhow::Result<MutexGuard<'_, WindowManager>> {
         lock!(self._window_manager)
     }
@@ -126,17 +176,109 @@ impl CoreApplication {
         lock!(self._api_manager)
     }
 
-    pub fn run(self: Arc<Self>, event_loop: utils::FrameEventLoop) -> anyhow::Result<()> {
+    /// Worker threads for blocking work outside the event loop, without locking the api manager.
+    pub fn thread_pool(&self) -> ArcMut<ThreadPool> {
+        self._thread_pool.clone()
+    }
+
+    pub fn socket_settings(&self) -> anyhow::Result<MutexGuard<'_, Option<options::SocketSettings>>> {
+        lock!(self._socket_settings)
+    }
+
+    pub fn lifecycle(&self) -> anyhow::Result<MutexGuard<'_, lifecycle::Lifecycle>> {
+        lock!(self._lifecycle)
+    }
+
+    pub fn fs_scope(&self) -> anyhow::Result<MutexGuard<'_, fs_scope::FsScope>> {
+        lock!(self._fs_scope)
+    }
+
+    /// Scope and audit log for APIs that write or delete by absolute path.
+    pub fn file_guard(&self) -> Arc<file_guard::FileGuard> {
+        self._file_guard.clone()
+    }
+
+    pub fn fs_watch(&self) -> anyhow::Result<MutexGuard<'_, fs_watch::FsWatchManager>> {
+        lock!(self._fs_watch)
+    }
+
+    pub fn i18n(&self) -> anyhow::Result<MutexGuard<'_, i18n::I18n>> {
+        lock!(self._i18n)
+    }
+
+    /// Runs the event loop and returns the exit code once the app has shut down.
+    pub fn run(self: Arc<Self>, mut event_loop: utils::FrameEventLoop) -> anyhow::Result<i32> {
         let app = &self.clone();
         // let menu_eventloop_proxy = app.proxy.clone();
         let options: &options::window::WindowConfig = &app.launch_info.options.window.clone();
-        let _main_win = app.window()?.open_window(&event_loop, options)?;
-        let _win_id = _main_win.id();
 
-        let mut handler = EventHandler::new(app.clone(), _win_id);
+        let mut handler = match &app.launch_info.options.splash {
+            Some(splash_options) => {
+                // Splash zuerst anzeigen, Handshake und Hauptfenster folgen asynchron
+                let splash = SplashScreen::open(app, &event_loop, splash_options)?;
+                let timeout = Duration::from_millis(splash_options.handshake_timeout_ms.unwrap_or(30_000));
+                app.spawn_handshake(splash.closed(), timeout);
+                EventHandler::new(app.clone(), None, Some(splash))
+            }
+            None => {
+                *app.socket_settings()? = app.launch_info.fetch_socket_settings()?;
+                let _main_win = app.window()?.open_window(&event_loop, options)?;
+                EventHandler::new(app.clone(), Some(_main_win.id()), None)
+            }
+        };
 
-        event_loop.run(move |event, target, control_flow| {
+        // Nur im Debug-Modus mit Ressourcen vom Dateisystem
+        if let Some(dir) = &app.launch_info.options.debug_resource {
+            let hot_reload = app.launch_info.options.hot_reload.clone().unwrap_or_default();
+            if hot_reload.enabled.unwrap_or(true) {
+                log_if_err!(hot_reload::watch(dir.clone(), hot_reload, app.proxy.clone()));
+            }
+        }
+
+        use tao::platform::run_return::EventLoopExtRunReturn;
+        let exit_code = event_loop.run_return(move |event, target, control_flow| {
             handler.handle(event, target, control_flow);
         });
+        Ok(exit_code)
+    }
+
+    /// Wartet im Hintergrund auf das Python-Backend und reicht Splash-Fortschritt weiter,
+    /// bis der Splash geschlossen ist.
+    fn spawn_handshake(self: &Arc<Self>, splash_closed: Arc<std::sync::atomic::AtomicBool>, timeout: Duration) {
+        let app = self.clone();
+        std::thread::spawn(move || {
+            let deadline = Instant::now() + timeout;
+            let mut ready = false;
+            let mut last_progress = None;
+
+            while !splash_closed.load(Ordering::SeqCst) {
+                if !ready {
+                    match app.launch_info.fetch_socket_settings() {
+                        Ok(settings) => {
+                            ready = true;
+                            if app.proxy.send_event(UserEvent::BackendReady(settings)).is_err() {
+                                return;
+                            }
+                        }
+                        Err(err) if Instant::now() >= deadline => {
+                            let _ = app.proxy.send_event(UserEvent::BackendFailed(err.to_string()));
+                            return;
+                        }
+                        Err(_) => {}
+                    }
+                }
+
+                if let Ok(Some(progress)) = app.launch_info.fetch_splash_progress() {
+                    if last_progress.as_ref() != Some(&progress) {
+                        last_progress = Some(progress.clone());
+                        if app.proxy.send_event(UserEvent::SplashProgress(progress)).is_err() {
+                            return;
+                        }
+                    }
+                }
+
+                std::thread::sleep(Duration::from_millis(100));
+            }
+        });
     }
 }
@@ -0,0 +1,204 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    log_err, log_if_err,
+    options::ShutdownHook,
+    utils::{get_json_sync, post_json_sync, UserEvent},
+    CoreApplication,
+};
+use anyhow::{anyhow, Result};
+use serde_json::json;
+use std::{sync::Arc, time::Duration};
+
+const DEFAULT_SHUTDOWN_TIMEOUT_MS: u64 = 5_000;
+/// How long `app.beforeQuit` handlers get to veto before the quit goes ahead.
+const BEFORE_QUIT_TIMEOUT_MS: u64 = 2_000;
+
+/// A quit waiting for the `app.beforeQuit` handlers of the windows and Python.
+#[derive(Debug)]
+struct PendingQuit {
+    id: u32,
+    exit_code: i32,
+    /// Replies still outstanding.
+    waiting: usize,
+    vetoed: bool,
+}
+
+/// Quit state shared between the event handler and the `app.*` API.
+#[derive(Debug, Default)]
+pub struct Lifecycle {
+    /// Like `window.blockCloseRequested`: quitting only emits `app.beforeQuit`
+    /// and the app decides itself whether to call `app.exit`.
+    pub quit_blocked: bool,
+    /// Exit code once the shutdown has started.
+    pub exit_code: Option<i32>,
+    pending_quit: Option<PendingQuit>,
+    next_quit_id: u32,
+}
+
+impl CoreApplication {
+    /// Emits `event` in every window and once to Python.
+    pub fn broadcast_event<P: serde::Serialize>(&self, event: &str, payload: P) -> Result<()> {
+        let payload = serde_json::to_value(payload)?;
+        let manager = self.window()?;
+        let windows = manager.list_windows();
+        for window in &windows {
+            window.send_ipc_event(event, payload.clone())?;
+        }
+        match windows.first() {
+            Some(window) => window.post_message(crate::window_manager::window::FrameWindow::python_event(
+                window.id, event, payload,
+            ))?,
+            None => self.post_backend_event(None, event, payload)?,
+        }
+        Ok(())
+    }
+
+    /// Delivers an event to Python over HTTP, for when no window is left to post it through.
+    pub fn post_backend_event<P: serde::Serialize>(
+        &self,
+        window_id: Option<u8>,
+        event: &str,
+        payload: P,
+    ) -> Result<()> {
+        let body = json!({
+            "windowId": window_id,
+            "event": event,
+            "data": serde_json::to_value(payload)?,
+        });
+        let url = format!(
+            "http://{}:{}/pyframe_app_event",
+            self.launch_info.options.host, self.launch_info.options.port
+        );
+        std::thread::spawn(move || {
+            if let Err(err) = post_json_sync(&url, &body) {
+                log_err!(format!("Failed to deliver app event: {}", err));
+            }
+        });
+        Ok(())
+    }
+
+    /// Asks to quit: emits `app.beforeQuit` with a `quitId` and waits for every window and
+    /// Python to answer via `app.beforeQuitReply`, at most `BEFORE_QUIT_TIMEOUT_MS`.
+    /// The event loop then gets `UserEvent::FinishQuit`. With `app.blockQuit` set the
+    /// event is only emitted.
+    pub fn request_quit(self: &Arc<Self>, exit_code: i32) -> Result<()> {
+        let windows = self.window()?.list_windows().len();
+        let (blocked, quit_id) = {
+            let mut lifecycle = self.lifecycle()?;
+            if lifecycle.exit_code.is_some() || lifecycle.pending_quit.is_some() {
+                return Ok(());
+            }
+            match lifecycle.quit_blocked {
+                true => (true, None),
+                false => {
+                    lifecycle.next_quit_id = lifecycle.next_quit_id.wrapping_add(1);
+                    let id = lifecycle.next_quit_id;
+                    lifecycle.pending_quit = Some(PendingQuit {
+                        id,
+                        exit_code,
+                        // Jedes Fenster und einmal Python
+                        waiting: windows + 1,
+                        vetoed: false,
+                    });
+                    (false, Some(id))
+                }
+            }
+        };
+        self.broadcast_event(
+            "app.beforeQuit",
+            json!({
+                "exitCode": exit_code,
+                "blocked": blocked,
+                "quitId": quit_id,
+            }),
+        )?;
+        if let Some(id) = quit_id {
+            let proxy = self.proxy.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(BEFORE_QUIT_TIMEOUT_MS));
+                let _ = proxy.send_event(UserEvent::FinishQuit(id));
+            });
+        }
+        Ok(())
+    }
+
+    /// Records one `app.beforeQuit` handler reply; the last one finishes the quit early.
+    pub fn reply_before_quit(&self, quit_id: u32, prevent: bool) -> Result<()> {
+        let mut lifecycle = self.lifecycle()?;
+        let Some(pending) = lifecycle.pending_quit.as_mut().filter(|pending| pending.id == quit_id) else {
+            return Ok(());
+        };
+        pending.vetoed |= prevent;
+        pending.waiting = pending.waiting.saturating_sub(1);
+        if pending.waiting == 0 {
+            self.proxy
+                .send_event(UserEvent::FinishQuit(quit_id))
+                .map_err(|_| anyhow!("Failed to send event"))?;
+        }
+        Ok(())
+    }
+
+    /// Ends the pending quit `quit_id`. Returns the exit code if nobody vetoed it.
+    pub fn finish_quit(&self, quit_id: u32) -> Result<Option<i32>> {
+        let mut lifecycle = self.lifecycle()?;
+        match lifecycle.pending_quit.take() {
+            Some(pending) if pending.id == quit_id => Ok((!pending.vetoed).then_some(pending.exit_code)),
+            // Verspäteter Timeout einer früheren Anfrage
+            other => {
+                lifecycle.pending_quit = other;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Runs the shutdown hook. The event loop exits on `UserEvent::ShutdownComplete`,
+    /// at the latest after the configured timeout.
+    pub fn start_shutdown(self: &Arc<Self>, exit_code: i32) -> Result<()> {
+        {
+            let mut lifecycle = self.lifecycle()?;
+            if lifecycle.exit_code.is_some() {
+                return Ok(());
+            }
+            lifecycle.exit_code = Some(exit_code);
+        }
+        self.broadcast_event("app.exit", json!({ "exitCode": exit_code }))?;
+
+        let options = self.launch_info.options.shutdown.clone().unwrap_or_default();
+        let timeout = Duration::from_millis(options.timeout_ms.unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_MS));
+
+        let proxy = self.proxy.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(timeout);
+            let _ = proxy.send_event(UserEvent::ShutdownComplete(exit_code));
+        });
+
+        match options.hook.unwrap_or_default() {
+            ShutdownHook::Http => {
+                let url = format!(
+                    "http://{}:{}/server_shutdown?exit_code={}",
+                    self.launch_info.options.host, self.launch_info.options.port, exit_code
+                );
+                let proxy = self.proxy.clone();
+                std::thread::spawn(move || {
+                    if let Err(err) = get_json_sync(&url) {
+                        log_err!(format!("Server shutdown failed: {}", err));
+                    }
+                    let _ = proxy.send_event(UserEvent::ShutdownComplete(exit_code));
+                });
+            }
+            ShutdownHook::Python if !self.window()?.list_windows().is_empty() => {
+                self.broadcast_event("app.shutdown", json!({ "exitCode": exit_code }))?;
+            }
+            ShutdownHook::Python | ShutdownHook::None => {
+                log_if_err!(self
+                    .proxy
+                    .send_event(UserEvent::ShutdownComplete(exit_code))
+                    .map_err(|_| anyhow!("Failed to send event")));
+            }
+        }
+        Ok(())
+    }
+}
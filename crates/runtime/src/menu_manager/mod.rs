@@ -4,7 +4,7 @@ use crate::hylper::{
 use crate::options::menu::MenuFrame;
 use crate::utils::ArcMut;
 use crate::utils::{arc_mut, IdCounter};
-use crate::{unsafe_impl_sync_send, CoreApplication};
+use crate::{i18n, unsafe_impl_sync_send, CoreApplication};
 use anyhow::{anyhow, Result};
 use muda::{Menu, MenuItemKind};
 use std::collections::HashMap;
@@ -16,6 +16,8 @@ pub struct PyFrameMenuManager {
     manager: Menu,
     pub items: HashMap<muda::MenuId, (MenuItemKind, Option<String>)>,
     id_counter: IdCounter, // Falls noch benötigt für andere Zwecke
+    /// Original `i18n:` labels, needed again after a locale change.
+    label_keys: HashMap<muda::MenuId, String>,
 }
 
 impl PyFrameMenuManager {
@@ -25,6 +27,7 @@ impl PyFrameMenuManager {
             manager: menu,
             items: HashMap::new(),
             id_counter: IdCounter::new(),
+            label_keys: HashMap::new(),
         };
         arc_mut(manager)
     }
@@ -36,11 +39,18 @@ impl PyFrameMenuManager {
         self.items.get(id).ok_or(anyhow!("MenuItem with id {:?} not found", id))
     }
 
-    pub fn register_with_options(&mut self, options: MenuFrame) -> Result<()> {
-        self.register_menu_items(options)
+    pub fn register_with_options(&mut self, options: MenuFrame, scale_factor: f64) -> Result<()> {
+        self.register_menu_items(options, scale_factor)
     }
 
-    pub fn register_menu_items(&mut self, menu_system: MenuFrame) -> Result<()> {
+    /// `scale_factor` is the one of the window the menu is shown in, icons are decoded for it.
+    pub fn register_menu_items(&mut self, menu_system: MenuFrame, scale_factor: f64) -> Result<()> {
+        // Icons kommen aus den Ressourcen der App, damit sie auch im Bundle funktionieren
+        let resource = self
+            .app
+            .as_ref()
+            .ok_or(anyhow!("Menu manager is not bound to an app."))?
+            .resource();
         if let Some(menu_items) = menu_system.menu_items {
             for item in menu_items {
                 let (menu_id, menu_item, command_id) = create_menuitem(item);
@@ -53,9 +63,12 @@ impl PyFrameMenuManager {
         // Füge Submenüs hinzu
         if let Some(sub_menus) = &menu_system.sub_menu {
             for submenu in sub_menus {
-                self.manager
-                    .append(&create_submenu(submenu.clone(), &mut self.items))
-                    .ok();
+                let submenu = create_submenu(resource.as_ref(), submenu.clone(), &mut self.items, scale_factor);
+                if self.manager.append(&submenu).is_ok() {
+                    // Nur für die Übersetzung des Titels, Submenüs lösen keine Events aus
+                    self.items
+                        .insert(submenu.id().clone(), (MenuItemKind::Submenu(submenu), None));
+                }
             }
         }
         if let Some(menu_items) = menu_system.check_menu {
@@ -68,7 +81,7 @@ impl PyFrameMenuManager {
 
         if let Some(menu_items) = menu_system.icon_menu {
             for item in menu_items {
-                let (menu_id, menu_item, command_id) = create_icon_menuitem(item);
+                let (menu_id, menu_item, command_id) = create_icon_menuitem(resource.as_ref(), item, scale_factor);
                 self.manager.append(&menu_item)?;
                 self.items.insert(menu_id, (MenuItemKind::Icon(menu_item), command_id));
             }
@@ -76,13 +89,38 @@ impl PyFrameMenuManager {
 
         if let Some(menu_items) = menu_system.predefined_menu {
             for item in menu_items {
-                let (menu_id, menu_item, command_id) = create_predefined_menu_item(item);
+                let (menu_id, menu_item, command_id) =
+                    create_predefined_menu_item(resource.as_ref(), item, scale_factor);
                 self.manager.append(&menu_item)?;
                 self.items
                     .insert(menu_id, (MenuItemKind::Predefined(menu_item), command_id));
             }
         }
 
+        self.translate_labels()
+    }
+
+    /// Replaces `i18n:<key>` labels with their translation in the current locale.
+    pub fn translate_labels(&mut self) -> Result<()> {
+        let app = self
+            .app
+            .clone()
+            .ok_or(anyhow!("Menu manager is not bound to an app."))?;
+        let mut i18n = app.i18n()?;
+        for (id, (kind, _)) in &self.items {
+            let label = match self.label_keys.get(id) {
+                Some(label) => label.clone(),
+                None => {
+                    let text = menu_text(kind);
+                    if !text.starts_with(i18n::LABEL_PREFIX) {
+                        continue;
+                    }
+                    self.label_keys.insert(id.clone(), text.clone());
+                    text
+                }
+            };
+            set_menu_text(kind, &i18n.label(&label));
+        }
         Ok(())
     }
 
@@ -102,6 +140,7 @@ impl PyFrameMenuManager {
 
         self.manager.remove(menu_item_ref)?;
         self.items.remove(menu_id);
+        self.label_keys.remove(menu_id);
 
         Ok(())
     }
@@ -122,3 +161,23 @@ impl PyFrameMenuManager {
         Ok(self.manager.clone())
     }
 }
+
+fn menu_text(kind: &MenuItemKind) -> String {
+    match kind {
+        MenuItemKind::MenuItem(item) => item.text(),
+        MenuItemKind::Check(item) => item.text(),
+        MenuItemKind::Icon(item) => item.text(),
+        MenuItemKind::Predefined(item) => item.text(),
+        MenuItemKind::Submenu(item) => item.text(),
+    }
+}
+
+fn set_menu_text(kind: &MenuItemKind, text: &str) {
+    match kind {
+        MenuItemKind::MenuItem(item) => item.set_text(text),
+        MenuItemKind::Check(item) => item.set_text(text),
+        MenuItemKind::Icon(item) => item.set_text(text),
+        MenuItemKind::Predefined(item) => item.set_text(text),
+        MenuItemKind::Submenu(item) => item.set_text(text),
+    }
+}
@@ -84,6 +84,215 @@ impl From<FrameBackgroundThrottlingPolicy> for wry::BackgroundThrottlingPolicy {
     }
 }
 
+/// What to do with a URL that a page tries to open outside of the app.
+#[derive(Deserialize, Default, Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NewWindowAction {
+    /// Open the URL in a new PyFrame window.
+    #[serde(rename = "window")]
+    Window,
+    /// Hand the URL over to the system browser.
+    #[serde(rename = "browser")]
+    Browser,
+    /// Block the request.
+    #[default]
+    #[serde(rename = "deny")]
+    Deny,
+}
+
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewWindowRule {
+    /// Glob pattern matched against the full URL, e.g. `https://github.com/*`.
+    pub pattern: String,
+    pub action: NewWindowAction,
+}
+
+/// Policy for `window.open`, `target="_blank"` and navigation away from the entry origin.
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewWindowOptions {
+    /// Action for URLs that match no rule. Defaults to `deny`.
+    pub default_action: Option<NewWindowAction>,
+    /// Rules are checked in order, the first match wins.
+    pub rules: Option<Vec<NewWindowRule>>,
+    /// Window configuration for `window` actions. Defaults to the opener's configuration.
+    pub window: Option<window::WindowConfig>,
+}
+
+/// Splash window shown while the Python backend starts.
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplashOptions {
+    /// Image from the resource bundle, rendered centered.
+    pub image: Option<String>,
+    /// HTML document from the resource bundle, takes precedence over `image`.
+    pub html: Option<String>,
+    pub size: Option<window::Size>,
+    pub transparent: Option<bool>,
+    /// Minimum time in milliseconds the splash stays visible.
+    pub min_display_ms: Option<u64>,
+    /// How long to wait for the backend handshake before giving up, in milliseconds.
+    pub handshake_timeout_ms: Option<u64>,
+}
+
+/// What happens with the Python backend when the app quits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, serde::Serialize)]
+pub enum ShutdownHook {
+    /// `GET /server_shutdown` on the backend.
+    #[default]
+    #[serde(rename = "http")]
+    Http,
+    /// Emits `app.shutdown` to Python and waits for `app.shutdownComplete`.
+    #[serde(rename = "python")]
+    Python,
+    #[serde(rename = "none")]
+    None,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShutdownOptions {
+    pub hook: Option<ShutdownHook>,
+    /// Hard limit in milliseconds for the hook, the app exits afterwards in any case.
+    pub timeout_ms: Option<u64>,
+}
+
+/// What closing the main window does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, serde::Serialize)]
+pub enum CloseBehavior {
+    /// Quits the app (`app.beforeQuit`, then shutdown).
+    #[serde(rename = "quit")]
+    Quit,
+    /// Hides the main window, the app keeps running in the tray.
+    #[serde(rename = "hide")]
+    Hide,
+    /// Destroys the main window, the app keeps running until an explicit `app.quit`.
+    #[serde(rename = "keepRunning")]
+    KeepRunning,
+}
+
+/// Extra response headers for `pyframe://` paths matching `pattern`.
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderRule {
+    /// Glob pattern matched against the request path, e.g. `/assets/*`.
+    pub pattern: String,
+    /// An empty value removes the header.
+    pub headers: HashMap<String, String>,
+}
+
+/// Headers of content served through the `pyframe://` protocol.
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityOptions {
+    /// Content-Security-Policy for HTML documents, `{nonce}` is replaced with the window's nonce.
+    /// Defaults to a strict policy, an empty string disables the header.
+    pub csp: Option<String>,
+    /// Rules are applied in order, later matches override earlier ones.
+    pub headers: Option<Vec<HeaderRule>>,
+    /// Adds the nonce to `<script>` and `<style>` tags of bundled HTML. Defaults to `true`.
+    pub inject_nonce: Option<bool>,
+}
+
+/// How bundled assets are served through the `pyframe://` protocol.
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServeOptions {
+    /// Document for unknown paths without file extension, e.g. `index.html` for client-side routing.
+    pub spa_fallback: Option<String>,
+    /// `Cache-Control` for assets, HTML documents always get `no-cache`. Defaults to `no-cache`.
+    pub cache_control: Option<String>,
+    /// Serve `<file>.br` / `<file>.gz` from the bundle when the webview accepts them. Defaults to `true`.
+    pub precompressed: Option<bool>,
+}
+
+impl ServeOptions {
+    /// Fallback document for `path`, only for paths that do not look like an asset.
+    pub fn spa_fallback_for(&self, path: &str) -> Option<&str> {
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+        match file_name.contains('.') {
+            true => None,
+            false => self
+                .spa_fallback
+                .as_deref()
+                .map(|fallback| fallback.trim_start_matches('/')),
+        }
+    }
+
+    pub fn cache_control(&self, is_html: bool) -> &str {
+        match (is_html, &self.cache_control) {
+            (false, Some(cache_control)) => cache_control,
+            _ => "no-cache",
+        }
+    }
+}
+
+/// Watching of `debug_resource`, has no effect without it.
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HotReloadOptions {
+    /// Defaults to `true`.
+    pub enabled: Option<bool>,
+    /// Quiet period in milliseconds before changes are applied.
+    pub debounce_ms: Option<u64>,
+    /// Glob patterns relative to the resource directory, replaces the default list.
+    pub ignore: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, serde::Serialize)]
+pub enum VerifyFailure {
+    /// Startup fails with the verification error.
+    #[default]
+    #[serde(rename = "abort")]
+    Abort,
+    /// Logs the error and serves the bundle anyway, only meant for development.
+    #[serde(rename = "warn")]
+    Warn,
+    /// Serves `fallback_dir` instead of the bundle.
+    #[serde(rename = "fallback")]
+    Fallback,
+}
+
+/// Verification and decryption of the resource bundle, see [`crate::resource_manager::bundle`].
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleOptions {
+    /// Base64 Ed25519 public key; when set the bundle must be signed with the matching key.
+    pub public_key: Option<String>,
+    /// Base64 32 byte key of an encrypted bundle.
+    pub encryption_key: Option<String>,
+    /// Defaults to `abort`.
+    pub on_verify_failure: Option<VerifyFailure>,
+    /// Resource directory used with `fallback`.
+    pub fallback_dir: Option<PathBuf>,
+}
+
+/// Guards for APIs that delete or write arbitrary paths, see [`crate::file_guard::FileGuard`].
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileOperationOptions {
+    /// Directories that may be written or deleted, in the `filesystem_scope` format.
+    /// Defaults to `$DATA`, `$CACHE` and `$TEMP`.
+    pub scope: Option<Vec<String>>,
+    /// Ask natively before deleting a directory outside of the app's own directories. Defaults to `false`.
+    pub confirm_recursive_delete: Option<bool>,
+    /// Append every operation to `audit.log` in `data_dir`. Defaults to `true`.
+    pub audit_log: Option<bool>,
+}
+
+/// Translation catalogs, see [`crate::i18n::I18n`].
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct I18nOptions {
+    /// Resource directory with one `<locale>.json` per locale, e.g. `de-AT.json`. Defaults to `locales`.
+    pub dir: Option<String>,
+    /// End of every fallback chain. Defaults to `en`.
+    pub fallback_locale: Option<String>,
+    /// Used instead of the system locale.
+    pub locale: Option<String>,
+}
+
 #[derive(Debug, serde::Serialize, Deserialize, Clone)]
 pub struct HeaderData {
     /// The key of the header.
@@ -116,6 +325,8 @@ pub struct AppOptions {
     pub internal_api: Option<bool>,
     pub debug_devtools: Option<bool>,
     pub debug_resource: Option<PathBuf>,
+    /// `.zip` archive served instead of the bundle; `debug_resource` takes precedence.
+    pub resource_archive: Option<PathBuf>,
     pub debug_entry: Option<String>,
     // window options
     #[serde(default)]
@@ -134,6 +345,32 @@ pub struct AppOptions {
     pub shortcuts: Option<FrameShortcutOption>,
     pub menu_mode: Option<MenuMode>,
     pub window_menu: Option<MenuFrame>,
+    pub new_window: Option<NewWindowOptions>,
+    pub splash: Option<SplashOptions>,
+    pub shutdown: Option<ShutdownOptions>,
+    pub close_behavior: Option<CloseBehavior>,
+    /// Global accelerator (e.g. `"CmdOrCtrl+Shift+Space"`) that shows the main window again.
+    pub reopen_shortcut: Option<String>,
+    /// Directories the `pyframe://filesystem/` host may serve and `fs.*` may read from, see
+    /// [`crate::fs_scope::FsScope`].
+    pub filesystem_scope: Option<Vec<String>>,
+    pub security: Option<SecurityOptions>,
+    pub serve: Option<ServeOptions>,
+    pub hot_reload: Option<HotReloadOptions>,
+    pub bundle: Option<BundleOptions>,
+    pub file_operations: Option<FileOperationOptions>,
+    pub i18n: Option<I18nOptions>,
+}
+
+impl AppOptions {
+    /// Explicit `close_behavior`, otherwise `Hide` for tray-only apps and `Quit` for everything else.
+    pub fn close_behavior(&self) -> CloseBehavior {
+        match (self.close_behavior, &self.menu_mode) {
+            (Some(behavior), _) => behavior,
+            (None, Some(MenuMode::Tray)) => CloseBehavior::Hide,
+            (None, _) => CloseBehavior::Quit,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -183,7 +420,6 @@ pub struct LaunchInfo {
     pub cache_dir: PathBuf,
     pub temp_dir: PathBuf,
     pub options: AppOptions,
-    pub socket_settings: Option<SocketSettings>,
 }
 impl LaunchInfo {
     pub fn new(raw_data: String) -> Result<Self> {
@@ -214,22 +450,31 @@ impl LaunchInfo {
         let data_dir = base_dirs.data_dir().join(&id_name);
         let cache_dir = base_dirs.cache_dir().join(&id_name);
 
-        let socket_settings = if options.internal_api.unwrap_or(true) {
-            let socket_host = options.host.clone();
-            let socket_port = options.port;
-            let server_url = format!("http://{}:{}/pyframe_socket_info", socket_host, socket_port);
-            Some(crate::utils::fetch_json_struct::<SocketSettings>(&server_url)?)
-        } else {
-            None
-        };
-
         Ok(Self {
             id_name,
             data_dir,
             cache_dir,
             temp_dir,
             options,
-            socket_settings,
         })
     }
+
+    /// Handshake mit dem Python-Backend, liefert `None` ohne interne API.
+    pub fn fetch_socket_settings(&self) -> Result<Option<SocketSettings>> {
+        if !self.options.internal_api.unwrap_or(true) {
+            return Ok(None);
+        }
+        let server_url = format!("http://{}:{}/pyframe_socket_info", self.options.host, self.options.port);
+        Ok(Some(crate::utils::fetch_json_struct::<SocketSettings>(&server_url)?))
+    }
+
+    /// Letzte Fortschrittsmeldung, die Python für den Splash gesetzt hat.
+    pub fn fetch_splash_progress(&self) -> Result<Option<String>> {
+        let server_url = format!(
+            "http://{}:{}/pyframe_splash_progress",
+            self.options.host, self.options.port
+        );
+        let json = crate::utils::get_json_sync(&server_url)?;
+        Ok(json["message"].as_str().map(str::to_string))
+    }
 }
@@ -71,6 +71,32 @@ pub struct MacOSWindowConfig {
     pub tabbing_identifier: Option<String>,
 }
 
+/// When a window becomes visible.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, serde::Serialize)]
+pub enum WindowShowWhen {
+    #[default]
+    #[serde(rename = "immediately")]
+    Immediately,
+    /// After the entry page finished loading or the page called `PyFrame.ready()`.
+    #[serde(rename = "load")]
+    Load,
+    /// Only after the page called `PyFrame.ready()`.
+    #[serde(rename = "ready")]
+    Ready,
+}
+
+/// Who handles files dropped onto the webview.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, serde::Serialize)]
+pub enum FileDropMode {
+    /// The page's HTML5 drag-and-drop handlers receive the drop as well.
+    #[default]
+    #[serde(rename = "page")]
+    Page,
+    /// Only the `window.fileDrop*` events are emitted, the OS default is blocked.
+    #[serde(rename = "native")]
+    Native,
+}
+
 #[derive(Debug, Clone, Deserialize, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WindowConfig {
@@ -98,6 +124,13 @@ pub struct WindowConfig {
     pub window_content_protection: Option<bool>,
     pub window_visible_on_all_workspaces: Option<bool>,
     pub window_background_color: Option<(u8, u8, u8, u8)>,
+    /// Creates the window hidden and shows it once the page is ready.
+    pub window_show_when: Option<WindowShowWhen>,
+    /// Fallback in milliseconds after which a hidden window is shown anyway.
+    pub window_show_timeout_ms: Option<u64>,
+    pub window_file_drop: Option<FileDropMode>,
+    /// Width of the resize border of undecorated windows in logical pixels, `0` disables it.
+    pub window_resize_inset: Option<f64>,
     pub webview_context_id: Option<String>,
     pub webview_id: Option<String>,
     pub webview_transparent: Option<bool>,
@@ -130,3 +163,20 @@ pub struct WindowConfig {
     #[serde(flatten)]
     pub macos_extra: Option<MacOSWindowConfig>,
 }
+
+impl WindowConfig {
+    pub fn resize_inset(&self) -> f64 {
+        self.window_resize_inset
+            .unwrap_or(crate::hylper::BORDERLESS_RESIZE_INSET)
+            .max(0.0)
+    }
+
+    /// Whether the window waits hidden for a ready signal, `None` if it is shown right away.
+    pub fn pending_show(&self) -> Option<WindowShowWhen> {
+        match self.window_show_when.unwrap_or_default() {
+            WindowShowWhen::Immediately => None,
+            _ if self.window_visible == Some(false) => None,
+            when => Some(when),
+        }
+    }
+}
@@ -0,0 +1,478 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::options::BundleOptions;
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::{
+    aead::{Aead, Payload},
+    ChaCha20Poly1305, KeyInit, Nonce,
+};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    io::Read,
+    path::Path,
+    sync::Arc,
+};
+
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Signature check or digest comparison of a bundle failed.
+#[derive(Debug)]
+pub struct VerificationFailed(pub String);
+
+impl std::fmt::Display for VerificationFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Resource bundle verification failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for VerificationFailed {}
+
+fn failed(reason: impl Into<String>) -> anyhow::Error {
+    VerificationFailed(reason.into()).into()
+}
+
+/// Dateiendungen, deren Inhalt schon komprimiert ist und deshalb gespeichert statt gepackt wird.
+const PRECOMPRESSED_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "webp", "avif", "ico", "mp3", "mp4", "m4a", "ogg", "opus", "webm", "woff", "woff2",
+    "zip", "gz", "br", "zst", "7z",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Compression {
+    #[serde(rename = "store")]
+    Store,
+    #[serde(rename = "deflate")]
+    Deflate,
+    #[serde(rename = "zstd")]
+    Zstd,
+}
+
+impl Compression {
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "store" => Ok(Self::Store),
+            "deflate" => Ok(Self::Deflate),
+            "zstd" => Ok(Self::Zstd),
+            _ => Err(anyhow!("Unknown compression '{}'", name)),
+        }
+    }
+
+    /// Default for `path` when the packer is not told otherwise.
+    pub fn for_path(path: &str) -> Self {
+        let extension = path.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase());
+        match extension {
+            Some(ext) if PRECOMPRESSED_EXTENSIONS.contains(&ext.as_str()) => Self::Store,
+            _ => Self::Zstd,
+        }
+    }
+
+    pub fn compress(self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Self::Store => Ok(data.to_vec()),
+            Self::Deflate => {
+                use std::io::Write;
+                let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::best());
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
+            }
+            Self::Zstd => Ok(zstd::bulk::compress(data, 19)?),
+        }
+    }
+
+    pub fn decompress(self, data: &[u8], size: usize) -> Result<Vec<u8>> {
+        let content = match self {
+            Self::Store => data.to_vec(),
+            Self::Deflate => {
+                let mut content = Vec::with_capacity(size);
+                flate2::read::DeflateDecoder::new(data).read_to_end(&mut content)?;
+                content
+            }
+            Self::Zstd => zstd::bulk::decompress(data, size)?,
+        };
+        if content.len() != size {
+            return Err(anyhow!(
+                "Corrupt bundle entry: expected {} bytes, got {}",
+                size,
+                content.len()
+            ));
+        }
+        Ok(content)
+    }
+}
+
+/// One file in `RESOURCE_DATA`; `offset`/`length` address the stored bytes, `size` is the original size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub offset: usize,
+    pub length: usize,
+    pub size: usize,
+    pub compression: Compression,
+    /// SHA-256 of the original content as hex, part of the signed manifest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Base64 ChaCha20-Poly1305 nonce of an encrypted entry; encrypted after compression.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexV2 {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub encrypted: bool,
+    /// SHA-256 of the whole `RESOURCE_DATA` as hex; checked at startup for signed bundles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_sha256: Option<String>,
+    pub entries: BTreeMap<String, Entry>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Index {
+    V2(IndexV2),
+    /// Alt: Offsets in den als Ganzes deflate-komprimierten Datenblock.
+    V1(HashMap<String, (usize, usize)>),
+}
+
+/// Rohdaten des Bundles; gemappt, eingebettet oder (v1) im Speicher entpackt.
+pub enum BundleData {
+    Owned(Vec<u8>),
+    #[allow(dead_code)]
+    Static(&'static [u8]),
+    Mapped(memmap2::Mmap),
+}
+
+impl std::ops::Deref for BundleData {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Owned(data) => data,
+            Self::Static(data) => data,
+            Self::Mapped(data) => data,
+        }
+    }
+}
+
+impl BundleData {
+    pub fn map(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        // Das Bundle liegt neben der Anwendung und wird zur Laufzeit nicht verändert
+        let data = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(Self::Mapped(data))
+    }
+}
+
+/// Keys from [`BundleOptions`], decoded once at startup.
+#[derive(Default)]
+pub struct BundleKeys {
+    pub public_key: Option<VerifyingKey>,
+    pub cipher: Option<ChaCha20Poly1305>,
+}
+
+impl BundleKeys {
+    pub fn new(options: &BundleOptions) -> Result<Self> {
+        let public_key = match &options.public_key {
+            Some(key) => Some(VerifyingKey::from_bytes(&decode_key(key, "public key")?)?),
+            None => None,
+        };
+        let cipher = match &options.encryption_key {
+            Some(key) => Some(ChaCha20Poly1305::new(&decode_key(key, "encryption key")?.into())),
+            None => None,
+        };
+        Ok(Self { public_key, cipher })
+    }
+
+    /// Checks the Ed25519 signature over the raw `RESOURCE_INDEXES`, which carries the digests.
+    pub fn verify_signature(&self, indexes_data: &[u8], signature: Option<&[u8]>) -> Result<()> {
+        let Some(public_key) = &self.public_key else {
+            return Ok(());
+        };
+        let signature = signature.ok_or_else(|| failed("the bundle is not signed"))?;
+        let signature = Signature::from_slice(signature).map_err(|_| failed("malformed signature"))?;
+        public_key
+            .verify(indexes_data, &signature)
+            .map_err(|_| failed("invalid signature"))
+    }
+
+    /// Decrypts the entry stored under `name`; the name is the associated data, so entries
+    /// cannot be swapped between names.
+    pub fn decrypt(&self, name: &str, entry: &Entry, stored: &[u8]) -> Result<Vec<u8>> {
+        let (Some(nonce), Some(cipher)) = (&entry.nonce, &self.cipher) else {
+            return Err(failed("the bundle is encrypted but no encryption key is configured"));
+        };
+        let nonce = STANDARD.decode(nonce)?;
+        if nonce.len() != 12 {
+            return Err(failed("malformed nonce"));
+        }
+        let payload = Payload {
+            msg: stored,
+            aad: name.as_bytes(),
+        };
+        cipher
+            .decrypt(Nonce::from_slice(&nonce), payload)
+            .map_err(|_| failed("entry could not be decrypted"))
+    }
+}
+
+/// Base64 encoded 32 byte key.
+pub fn decode_key(key: &str, what: &str) -> Result<[u8; 32]> {
+    STANDARD
+        .decode(key.trim())?
+        .try_into()
+        .map_err(|_| anyhow!("The {} must be 32 bytes", what))
+}
+
+/// Base64 public key for a base64 Ed25519 signing key, for [`BundleOptions::public_key`].
+pub fn public_key_for(signing_key: &str) -> Result<String> {
+    let signing_key = SigningKey::from_bytes(&decode_key(signing_key, "signing key")?);
+    Ok(STANDARD.encode(signing_key.verifying_key().to_bytes()))
+}
+
+pub fn sign(indexes_data: &[u8], signing_key: &[u8; 32]) -> Vec<u8> {
+    SigningKey::from_bytes(signing_key)
+        .sign(indexes_data)
+        .to_bytes()
+        .to_vec()
+}
+
+/// Encrypts a stored entry with `name` as associated data. The nonce is an HMAC-SHA256 of name
+/// and content under the key, so packing stays deterministic without revealing anything about
+/// the content to someone who does not have the key.
+pub fn encrypt(name: &str, stored: &[u8], key: &[u8; 32]) -> Result<(Vec<u8>, String)> {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).map_err(|_| anyhow!("Invalid encryption key"))?;
+    mac.update(name.as_bytes());
+    mac.update(&[0u8]);
+    mac.update(stored);
+    let digest = mac.finalize().into_bytes();
+    let nonce = Nonce::from_slice(&digest[..12]);
+    let payload = Payload {
+        msg: stored,
+        aad: name.as_bytes(),
+    };
+    let encrypted = ChaCha20Poly1305::new(key.into())
+        .encrypt(nonce, payload)
+        .map_err(|_| anyhow!("Encryption of {} failed", name))?;
+    Ok((encrypted, STANDARD.encode(nonce)))
+}
+
+/// Whether `offset..offset + length` lies inside `len` bytes; an overflowing end never does.
+fn in_bounds(offset: usize, length: usize, len: usize) -> bool {
+    offset.checked_add(length).is_some_and(|end| end <= len)
+}
+
+/// Compares the whole `RESOURCE_DATA` with [`IndexV2::data_sha256`] from the signed index.
+pub fn check_data(expected: Option<&str>, data: &[u8]) -> Result<()> {
+    let expected = expected.ok_or_else(|| failed("the manifest is missing the data digest"))?;
+    if hex::encode(Sha256::digest(data)) != expected {
+        return Err(failed("RESOURCE_DATA does not match the manifest"));
+    }
+    Ok(())
+}
+
+/// Parses `RESOURCE_INDEXES` in either format and returns the entries, the data and the
+/// data digest. A v1 bundle is inflated once and then addressed like a v2 bundle with stored entries.
+pub fn open(
+    indexes_data: &[u8],
+    data: BundleData,
+    keys: &BundleKeys,
+) -> Result<(HashMap<String, Entry>, BundleData, Option<String>)> {
+    match serde_json::from_slice::<Index>(indexes_data)? {
+        Index::V2(index) if index.version == FORMAT_VERSION => {
+            if index.encrypted && keys.cipher.is_none() {
+                return Err(failed("the bundle is encrypted but no encryption key is configured"));
+            }
+            if keys.public_key.is_some() && index.entries.values().any(|entry| entry.sha256.is_none()) {
+                return Err(failed("the manifest is missing digests"));
+            }
+            let entries: HashMap<String, Entry> = index.entries.into_iter().collect();
+            if let Some((name, _)) = entries
+                .iter()
+                .find(|(_, entry)| !in_bounds(entry.offset, entry.length, data.len()))
+            {
+                return Err(anyhow!("Bundle entry {} is out of bounds", name));
+            }
+            Ok((entries, data, index.data_sha256))
+        }
+        Index::V2(index) => Err(anyhow!("Unsupported bundle version {}", index.version)),
+        // v1 hat kein Manifest und kann deshalb nicht verifiziert werden
+        Index::V1(_) if keys.public_key.is_some() => Err(failed("v1 bundles cannot be signed")),
+        Index::V1(indexes) => {
+            let mut inflated = Vec::new();
+            flate2::read::DeflateDecoder::new(&data[..]).read_to_end(&mut inflated)?;
+            let mut entries = HashMap::with_capacity(indexes.len());
+            for (name, (offset, length)) in indexes {
+                if !in_bounds(offset, length, inflated.len()) {
+                    return Err(anyhow!("Bundle entry {} is out of bounds", name));
+                }
+                let entry = Entry {
+                    offset,
+                    length,
+                    size: length,
+                    compression: Compression::Store,
+                    sha256: None,
+                    nonce: None,
+                };
+                entries.insert(name, entry);
+            }
+            Ok((entries, BundleData::Owned(inflated), None))
+        }
+    }
+}
+
+/// Kleiner LRU für entpackte Einträge, begrenzt über die Gesamtgröße in Bytes.
+pub struct LruCache {
+    capacity: usize,
+    used: usize,
+    entries: VecDeque<(String, Arc<Vec<u8>>)>,
+}
+
+impl LruCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            used: 0,
+            entries: VecDeque::new(),
+        }
+    }
+
+    pub fn get(&mut self, key: &str) -> Option<Arc<Vec<u8>>> {
+        let index = self.entries.iter().position(|(name, _)| name == key)?;
+        let entry = self.entries.remove(index)?;
+        let value = entry.1.clone();
+        self.entries.push_front(entry);
+        Some(value)
+    }
+
+    pub fn insert(&mut self, key: String, value: Arc<Vec<u8>>) {
+        // Einträge größer als ein Viertel des Caches würden ihn nur leeren
+        if value.len() > self.capacity / 4 {
+            return;
+        }
+        if let Some(index) = self.entries.iter().position(|(name, _)| *name == key) {
+            if let Some((_, old)) = self.entries.remove(index) {
+                self.used -= old.len();
+            }
+        }
+        self.used += value.len();
+        self.entries.push_front((key, value));
+        while self.used > self.capacity {
+            match self.entries.pop_back() {
+                Some((_, evicted)) => self.used -= evicted.len(),
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNING_KEY: [u8; 32] = [1; 32];
+    const ENCRYPTION_KEY: [u8; 32] = [7; 32];
+
+    fn keys() -> BundleKeys {
+        BundleKeys::new(&BundleOptions {
+            public_key: Some(public_key_for(&STANDARD.encode(SIGNING_KEY)).unwrap()),
+            encryption_key: Some(STANDARD.encode(ENCRYPTION_KEY)),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn stored_entry(offset: usize, length: usize) -> Entry {
+        Entry {
+            offset,
+            length,
+            size: length,
+            compression: Compression::Store,
+            sha256: Some(hex::encode(Sha256::digest(b""))),
+            nonce: None,
+        }
+    }
+
+    fn index(data: &[u8], entries: BTreeMap<String, Entry>) -> Vec<u8> {
+        let index = IndexV2 {
+            version: FORMAT_VERSION,
+            encrypted: false,
+            data_sha256: Some(hex::encode(Sha256::digest(data))),
+            entries,
+        };
+        serde_json::to_vec(&index).unwrap()
+    }
+
+    #[test]
+    fn tampered_index_or_data_is_rejected() {
+        let keys = keys();
+        let data = b"hello world".to_vec();
+        let indexes_data = index(&data, BTreeMap::from([("a.txt".to_string(), stored_entry(0, 11))]));
+        let signature = sign(&indexes_data, &SIGNING_KEY);
+
+        keys.verify_signature(&indexes_data, Some(&signature)).unwrap();
+        let (_, data, digest) = open(&indexes_data, BundleData::Owned(data), &keys).unwrap();
+        check_data(digest.as_deref(), &data).unwrap();
+
+        let mut tampered_index = indexes_data.clone();
+        *tampered_index.last_mut().unwrap() = b' ';
+        let err = keys.verify_signature(&tampered_index, Some(&signature)).unwrap_err();
+        assert!(err.is::<VerificationFailed>());
+        let err = keys.verify_signature(&indexes_data, None).unwrap_err();
+        assert!(err.is::<VerificationFailed>());
+        let err = keys.verify_signature(&indexes_data, Some(&signature[1..])).unwrap_err();
+        assert!(err.is::<VerificationFailed>());
+
+        let err = check_data(digest.as_deref(), b"hello World").unwrap_err();
+        assert!(err.is::<VerificationFailed>());
+        assert!(check_data(None, &data).unwrap_err().is::<VerificationFailed>());
+    }
+
+    #[test]
+    fn out_of_bounds_entries_are_rejected() {
+        assert!(in_bounds(0, 4, 4));
+        assert!(in_bounds(4, 0, 4));
+        assert!(!in_bounds(1, 4, 4));
+        assert!(!in_bounds(usize::MAX, 2, 10));
+
+        let keys = BundleKeys::default();
+        let data = b"0123".to_vec();
+        for entry in [stored_entry(2, 3), stored_entry(usize::MAX, 2)] {
+            let indexes_data = index(&data, BTreeMap::from([("a.txt".to_string(), entry)]));
+            assert!(open(&indexes_data, BundleData::Owned(data.clone()), &keys).is_err());
+        }
+        let indexes_data = index(&data, BTreeMap::from([("a.txt".to_string(), stored_entry(0, 4))]));
+        assert!(open(&indexes_data, BundleData::Owned(data), &keys).is_ok());
+    }
+
+    #[test]
+    fn encrypted_entries_round_trip() {
+        let keys = keys();
+        let (encrypted, nonce) = encrypt("a.txt", b"hello", &ENCRYPTION_KEY).unwrap();
+        assert_ne!(&encrypted[..5], b"hello");
+        // Gleiche Eingabe, gleiche Nonce: das Packen bleibt deterministisch
+        assert_eq!(
+            encrypt("a.txt", b"hello", &ENCRYPTION_KEY).unwrap(),
+            (encrypted.clone(), nonce.clone())
+        );
+        assert_ne!(encrypt("b.txt", b"hello", &ENCRYPTION_KEY).unwrap().1, nonce);
+
+        let entry = Entry {
+            nonce: Some(nonce),
+            ..stored_entry(0, encrypted.len())
+        };
+        assert_eq!(keys.decrypt("a.txt", &entry, &encrypted).unwrap(), b"hello");
+        // Der Name ist die Associated Data, vertauschte Einträge fallen auf
+        assert!(keys.decrypt("b.txt", &entry, &encrypted).is_err());
+        let mut tampered = encrypted.clone();
+        tampered[0] ^= 1;
+        assert!(keys.decrypt("a.txt", &entry, &tampered).is_err());
+        assert!(BundleKeys::default().decrypt("a.txt", &entry, &encrypted).is_err());
+    }
+}
@@ -3,58 +3,148 @@
 // SPDX-License-Identifier: MIT
 
 use anyhow::{anyhow, Result};
-use std::io::Cursor;
+use image::{imageops::FilterType, DynamicImage, ImageFormat};
 use tao::window::Icon;
+use tray_icon::Icon as TrayIcon;
 
-pub fn png_to_icon(png_data: &[u8]) -> Result<tao::window::Icon> {
-    use png::ColorType;
-
-    let mut cursor = Cursor::new(png_data);
-
-    let mut decoder = png::Decoder::new(&mut cursor);
-    decoder.set_transformations(png::Transformations::EXPAND);
-    let mut reader = decoder.read_info()?;
-    let mut buffer = vec![0; reader.output_buffer_size()];
-    let info = reader.next_frame(&mut buffer)?;
-
-    // Convert the pixel data to RGBA format
-    let rgba = match info.color_type {
-        ColorType::Rgba => buffer,
-        ColorType::Rgb => {
-            let mut rgba = Vec::with_capacity(info.width as usize * info.height as usize * 4);
-            for chunk in buffer.chunks(3) {
-                rgba.push(chunk[0]);
-                rgba.push(chunk[1]);
-                rgba.push(chunk[2]);
-                rgba.push(255);
-            }
-            rgba
-        }
-        ColorType::Grayscale => {
-            let mut rgba = Vec::with_capacity(info.width as usize * info.height as usize * 4);
-            for chunk in buffer.iter() {
-                rgba.push(*chunk);
-                rgba.push(*chunk);
-                rgba.push(*chunk);
-                rgba.push(255);
-            }
-            rgba
-        }
-        ColorType::GrayscaleAlpha => {
-            let mut rgba = Vec::with_capacity(info.width as usize * info.height as usize * 4);
-            for chunk in buffer.chunks(2) {
-                rgba.push(chunk[0]);
-                rgba.push(chunk[0]);
-                rgba.push(chunk[0]);
-                rgba.push(chunk[1]);
-            }
-            rgba
-        }
-        _ => return Err(anyhow!("Unsupported color type")),
+/// Where an icon is shown; decides the pixel size that gets decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconUse {
+    Window,
+    Tray,
+    Menu,
+}
+
+impl IconUse {
+    /// Largest edge in physical pixels at `scale_factor`; the OS scales down from there.
+    pub fn size(self, scale_factor: f64) -> u32 {
+        let logical = match self {
+            IconUse::Window => 128.0,
+            IconUse::Tray => 32.0,
+            IconUse::Menu => 16.0,
+        };
+        (logical * scale_factor.max(1.0)).ceil() as u32
+    }
+}
+
+pub struct RgbaIcon {
+    pub rgba: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Decodes PNG, ICO, JPEG, WebP, GIF, BMP or SVG into RGBA, picking or scaling to fit `usage`
+/// on a display with `scale_factor`. `path` is only used to recognise SVG by its extension.
+pub fn decode_icon(data: &[u8], path: &str, usage: IconUse, scale_factor: f64) -> Result<RgbaIcon> {
+    let size = usage.size(scale_factor);
+    if is_svg(data, path) {
+        return rasterize_svg(data, size);
+    }
+    let image = match image::guess_format(data)? {
+        ImageFormat::Ico => image::load_from_memory_with_format(&best_ico_entry(data, size)?, ImageFormat::Ico)?,
+        format => image::load_from_memory_with_format(data, format)?,
+    };
+    // Nur verkleinern; kleine Bilder hochzuskalieren macht sie nicht schärfer
+    let image = match image.width().max(image.height()) > size {
+        true => image.resize(size, size, FilterType::Lanczos3),
+        false => image,
+    };
+    Ok(to_rgba(image))
+}
+
+pub fn window_icon(data: &[u8], path: &str, scale_factor: f64) -> Result<Icon> {
+    let icon = decode_icon(data, path, IconUse::Window, scale_factor)?;
+    Ok(Icon::from_rgba(icon.rgba, icon.width, icon.height)?)
+}
+
+pub fn tray_icon(data: &[u8], path: &str, scale_factor: f64) -> Result<TrayIcon> {
+    let icon = decode_icon(data, path, IconUse::Tray, scale_factor)?;
+    Ok(TrayIcon::from_rgba(icon.rgba, icon.width, icon.height)?)
+}
+
+pub fn menu_icon(data: &[u8], path: &str, scale_factor: f64) -> Result<muda::Icon> {
+    let icon = decode_icon(data, path, IconUse::Menu, scale_factor)?;
+    Ok(muda::Icon::from_rgba(icon.rgba, icon.width, icon.height)?)
+}
+
+fn to_rgba(image: DynamicImage) -> RgbaIcon {
+    let buffer = image.into_rgba8();
+    let (width, height) = buffer.dimensions();
+    RgbaIcon {
+        rgba: buffer.into_raw(),
+        width,
+        height,
+    }
+}
+
+fn is_svg(data: &[u8], path: &str) -> bool {
+    if path.to_ascii_lowercase().ends_with(".svg") {
+        return true;
+    }
+    let head = String::from_utf8_lossy(&data[..data.len().min(256)]);
+    let head = head.trim_start();
+    head.starts_with("<svg") || (head.starts_with("<?xml") && head.contains("<svg"))
+}
+
+fn rasterize_svg(data: &[u8], size: u32) -> Result<RgbaIcon> {
+    let tree = resvg::usvg::Tree::from_data(data, &resvg::usvg::Options::default())?;
+    let tree_size = tree.size();
+    let scale = size as f32 / tree_size.width().max(tree_size.height());
+    let width = ((tree_size.width() * scale).round() as u32).max(1);
+    let height = ((tree_size.height() * scale).round() as u32).max(1);
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(width, height).ok_or(anyhow!("Invalid SVG size."))?;
+    resvg::render(
+        &tree,
+        resvg::tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+    // tiny-skia arbeitet mit vormultipliziertem Alpha
+    let rgba = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    Ok(RgbaIcon { rgba, width, height })
+}
+
+/// The `image` crate always decodes the largest ICO entry. This picks the smallest entry that
+/// is at least `size` (or the largest one) and returns it as a single-entry ICO.
+fn best_ico_entry(data: &[u8], size: u32) -> Result<Vec<u8>> {
+    let u16_at = |offset: usize| data.get(offset..offset + 2).map(|b| u16::from_le_bytes([b[0], b[1]]));
+    let u32_at = |offset: usize| {
+        data.get(offset..offset + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
     };
+    let count = u16_at(4).ok_or(anyhow!("Invalid ICO header."))? as usize;
 
-    let width = info.width;
-    let height = info.height;
+    let mut entries = Vec::with_capacity(count);
+    for index in 0..count {
+        let entry = 6 + index * 16;
+        let header = data.get(entry..entry + 16).ok_or(anyhow!("Invalid ICO directory."))?;
+        // 0 steht für 256 Pixel
+        let edge = match header[0].max(header[1]) {
+            0 => 256,
+            edge => edge as u32,
+        };
+        let length = u32_at(entry + 8).ok_or(anyhow!("Invalid ICO directory."))? as usize;
+        let offset = u32_at(entry + 12).ok_or(anyhow!("Invalid ICO directory."))? as usize;
+        let image = data.get(offset..offset + length).ok_or(anyhow!("Invalid ICO entry."))?;
+        entries.push((edge, header, image));
+    }
+    let (_, header, image) = entries
+        .iter()
+        .filter(|(edge, _, _)| *edge >= size)
+        .min_by_key(|(edge, _, _)| *edge)
+        .or_else(|| entries.iter().max_by_key(|(edge, _, _)| *edge))
+        .ok_or(anyhow!("Empty ICO file."))?;
 
-    Ok(Icon::from_rgba(rgba, width, height)?)
+    let mut ico = Vec::with_capacity(22 + image.len());
+    ico.extend_from_slice(&[0, 0, 1, 0, 1, 0]);
+    ico.extend_from_slice(&header[..12]);
+    ico.extend_from_slice(&22u32.to_le_bytes());
+    ico.extend_from_slice(image);
+    Ok(ico)
 }
@@ -0,0 +1,186 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use super::{ResourceManager, ResourceMetadata};
+use crate::{
+    lock,
+    utils::{arc, arc_mut, ArcMut},
+};
+use anyhow::{anyhow, Result};
+use std::{collections::BTreeSet, path::Path, sync::Arc};
+use tao::window::Icon;
+use tray_icon::Icon as TrayIcon;
+
+/// Layers created by `CoreApplication::new`, they cannot be unmounted.
+pub const BASE_LAYER: &str = "base";
+pub const OVERRIDES_LAYER: &str = "overrides";
+/// Directory below `data_dir` whose files shadow the bundle, e.g. for themes.
+pub const OVERRIDES_DIR: &str = "resources";
+
+struct Layer {
+    id: String,
+    /// Empty or ending with `/`; the layer only answers paths below it.
+    prefix: String,
+    manager: Arc<dyn ResourceManager>,
+}
+
+impl Layer {
+    fn inner_path<'a>(&self, path: &'a str) -> Option<&'a str> {
+        path.strip_prefix(self.prefix.as_str())
+    }
+}
+
+/// Stack of resource sources. Lookups start at the most recently mounted layer, so mounted
+/// directories and archives shadow the override directory, which in turn shadows the bundle.
+/// A signed bundle is never shadowed: its entries are looked up before any layer.
+pub struct LayeredResource {
+    layers: ArcMut<Vec<Layer>>,
+    /// The base is a signed bundle; layers only add paths the bundle does not have.
+    signed: bool,
+}
+
+impl std::fmt::Debug for LayeredResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LayeredResource")
+            .field("layers", &self.layer_ids())
+            .finish()
+    }
+}
+
+impl LayeredResource {
+    pub fn new(base: Arc<dyn ResourceManager>, signed: bool) -> Arc<Self> {
+        arc(Self {
+            layers: arc_mut(vec![Layer {
+                id: BASE_LAYER.to_string(),
+                prefix: String::new(),
+                manager: base,
+            }]),
+            signed,
+        })
+    }
+
+    /// Puts `manager` on top of the stack; an existing layer with the same id is replaced in place.
+    /// With a `prefix` like `plugins/chart` the layer serves `plugins/chart/<path>` from its `<path>`.
+    /// Over a signed bundle only prefixed layers are accepted, and they cannot shadow bundle entries.
+    pub fn mount(&self, id: &str, prefix: Option<&str>, manager: Arc<dyn ResourceManager>) -> Result<()> {
+        if id == BASE_LAYER {
+            return Err(anyhow!("The base layer cannot be replaced."));
+        }
+        let prefix = match prefix.map(|prefix| prefix.trim_matches('/')) {
+            Some(prefix) if !prefix.is_empty() => format!("{}/", prefix),
+            _ => String::new(),
+        };
+        if self.signed && prefix.is_empty() {
+            return Err(anyhow!("Layers over a signed bundle need a prefix."));
+        }
+        let mut layers = lock!(self.layers)?;
+        match layers.iter_mut().find(|layer| layer.id == id) {
+            Some(layer) => {
+                layer.prefix = prefix;
+                layer.manager = manager;
+            }
+            None => layers.push(Layer {
+                id: id.to_string(),
+                prefix,
+                manager,
+            }),
+        }
+        Ok(())
+    }
+
+    pub fn unmount(&self, id: &str) -> Result<()> {
+        if id == BASE_LAYER || id == OVERRIDES_LAYER {
+            return Err(anyhow!("The {} layer cannot be unmounted.", id));
+        }
+        let mut layers = lock!(self.layers)?;
+        let count = layers.len();
+        layers.retain(|layer| layer.id != id);
+        match layers.len() < count {
+            true => Ok(()),
+            false => Err(anyhow!("No layer named {}.", id)),
+        }
+    }
+
+    /// Layer ids from the bottom to the top of the stack.
+    pub fn layer_ids(&self) -> Vec<String> {
+        lock!(self.layers)
+            .map(|layers| layers.iter().map(|layer| layer.id.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Topmost layer that has `path`, together with the path inside that layer.
+    fn resolve<'a>(&self, path: &'a str) -> Result<(Arc<dyn ResourceManager>, &'a str)> {
+        let layers = lock!(self.layers)?;
+        // Signierte Einträge gehen jeder Ebene vor, sonst könnte ein Präfix sie verdecken
+        let signed_base = layers.first().filter(|_| self.signed);
+        signed_base
+            .into_iter()
+            .chain(layers.iter().rev())
+            .find_map(|layer| {
+                let inner = layer.inner_path(path)?;
+                layer.manager.exists(inner).then(|| (layer.manager.clone(), inner))
+            })
+            .ok_or(anyhow!("File not found."))
+    }
+
+    fn base(&self) -> Result<Arc<dyn ResourceManager>> {
+        lock!(self.layers)?
+            .first()
+            .map(|layer| layer.manager.clone())
+            .ok_or(anyhow!("No base layer."))
+    }
+}
+
+impl ResourceManager for LayeredResource {
+    fn exists(&self, path: &str) -> bool {
+        self.resolve(path).is_ok()
+    }
+
+    fn load(&self, path: &str) -> Result<Vec<u8>> {
+        let (manager, path) = self.resolve(path)?;
+        manager.load(path)
+    }
+
+    fn extract(&self, from: &str, to: &Path) -> Result<()> {
+        let (manager, from) = self.resolve(from)?;
+        manager.extract(from, to)
+    }
+
+    fn load_icon(&self, path: &str, scale_factor: f64) -> Result<Icon> {
+        let (manager, path) = self.resolve(path)?;
+        manager.load_icon(path, scale_factor)
+    }
+
+    fn load_tray_icon(&self, path: &str, scale_factor: f64) -> Result<TrayIcon> {
+        let (manager, path) = self.resolve(path)?;
+        manager.load_tray_icon(path, scale_factor)
+    }
+
+    fn load_icon_from_bytes(&self, data: &[u8], scale_factor: f64) -> Result<Icon> {
+        self.base()?.load_icon_from_bytes(data, scale_factor)
+    }
+
+    fn hash(&self, path: &str) -> Result<String> {
+        let (manager, path) = self.resolve(path)?;
+        manager.hash(path)
+    }
+
+    /// Union of all layers, every path once.
+    fn list(&self) -> Result<Vec<String>> {
+        let layers: Vec<_> = lock!(self.layers)?
+            .iter()
+            .map(|layer| (layer.prefix.clone(), layer.manager.clone()))
+            .collect();
+        let mut files = BTreeSet::new();
+        for (prefix, manager) in layers {
+            files.extend(manager.list()?.into_iter().map(|file| format!("{}{}", prefix, file)));
+        }
+        Ok(files.into_iter().collect())
+    }
+
+    fn metadata(&self, path: &str) -> Result<ResourceMetadata> {
+        let (manager, path) = self.resolve(path)?;
+        manager.metadata(path)
+    }
+}
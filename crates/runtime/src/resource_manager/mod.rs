@@ -2,14 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-mod image_utils;
+pub mod bundle;
+pub mod image_utils;
+pub mod layered;
+pub mod packer;
 #[cfg(target_os = "windows")]
 mod win_utils;
+pub mod zip_resource;
 
 use anyhow::{Ok, Result};
+use bundle::{BundleData, BundleKeys, Compression, Entry, LruCache, VerificationFailed};
+use sha2::{Digest, Sha256};
 use std::{
-    collections::HashMap,
-    io::Read,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
     sync::{Arc, Mutex},
 };
@@ -17,12 +22,18 @@ use tao::window::Icon;
 use tray_icon::Icon as TrayIcon;
 
 use crate::{
-    lock,
+    lock, log_err,
+    options::{BundleOptions, VerifyFailure},
     utils::{arc, arc_mut, ArcMut},
 };
 
 type IconCache = HashMap<String, Icon>;
 
+/// Icons are cached per path and scale factor.
+pub(crate) fn icon_cache_key(path: &str, scale_factor: f64) -> String {
+    format!("{}@{}x", path, scale_factor)
+}
+
 pub trait ResourceManager: std::fmt::Debug + Send + Sync {
     #[allow(dead_code)]
     fn exists(&self, path: &str) -> bool;
@@ -30,12 +41,37 @@ pub trait ResourceManager: std::fmt::Debug + Send + Sync {
     fn load(&self, path: &str) -> Result<Vec<u8>>;
     #[allow(dead_code)]
     fn extract(&self, from: &str, to: &Path) -> Result<()>;
+    /// Icons are decoded for the display `scale_factor` they are shown on.
     #[allow(dead_code)]
-    fn load_icon(&self, path: &str) -> Result<Icon>;
-    fn load_tray_icon(&self, path: &str) -> Result<TrayIcon>;
+    fn load_icon(&self, path: &str, scale_factor: f64) -> Result<Icon>;
+    fn load_tray_icon(&self, path: &str, scale_factor: f64) -> Result<TrayIcon>;
     // ➜ NEU: Direkt aus Bytes laden
     #[allow(dead_code)]
-    fn load_icon_from_bytes(&self, data: &[u8]) -> Result<Icon>;
+    fn load_icon_from_bytes(&self, data: &[u8], scale_factor: f64) -> Result<Icon>;
+    /// SHA-256 des Inhalts als Hex, Grundlage für `ETag`.
+    fn hash(&self, path: &str) -> Result<String> {
+        Ok(hex::encode(Sha256::digest(self.load(path)?)))
+    }
+    /// Alle Dateien als `/`-getrennte relative Pfade, sortiert.
+    fn list(&self) -> Result<Vec<String>>;
+    fn metadata(&self, path: &str) -> Result<ResourceMetadata> {
+        Ok(ResourceMetadata {
+            size: self.load(path)?.len() as u64,
+            ..Default::default()
+        })
+    }
+}
+
+/// Angaben zu einer Ressource, soweit die Quelle sie kennt.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceMetadata {
+    pub size: u64,
+    pub compressed_size: Option<u64>,
+    /// `store`, `deflate` or `zstd`.
+    pub compression: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub modified: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -72,120 +108,244 @@ impl ResourceManager for FileSystemResource {
         fs_extra::file::copy(self.root_dir.join(from), to, &fs_extra::file::CopyOptions::new())?;
         Ok(())
     }
-    fn load_tray_icon(&self, path: &str) -> Result<TrayIcon> {
-        let absolute_path = self.root_dir.join(path);
-        let data = std::fs::read(&absolute_path)?;
 
-        // PNG-Daten dekodieren
-        let decoder = png::Decoder::new(std::io::Cursor::new(&data));
-        let mut reader = decoder.read_info()?;
-        let mut buf = vec![0; reader.output_buffer_size()];
-        let info = reader.next_frame(&mut buf)?;
+    fn list(&self) -> Result<Vec<String>> {
+        let mut files = Vec::new();
+        for entry in walkdir::WalkDir::new(&self.root_dir).sort_by_file_name() {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                let relative = entry.path().strip_prefix(&self.root_dir)?;
+                files.push(relative.to_string_lossy().replace('\\', "/"));
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
 
-        // Icon aus RGBA erstellen
-        let icon = TrayIcon::from_rgba(buf, info.width, info.height)?;
-        Ok(icon)
+    fn metadata(&self, path: &str) -> Result<ResourceMetadata> {
+        let metadata = std::fs::metadata(self.root_dir.join(path))?;
+        Ok(ResourceMetadata {
+            size: metadata.len(),
+            modified: metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs()),
+            ..Default::default()
+        })
+    }
+    fn load_tray_icon(&self, path: &str, scale_factor: f64) -> Result<TrayIcon> {
+        image_utils::tray_icon(&self.load(path)?, path, scale_factor)
     }
 
-    fn load_icon_from_bytes(&self, data: &[u8]) -> Result<Icon> {
-        // ➜ einfach die bestehende Hilfsmethode nutzen
-        let icon = image_utils::png_to_icon(data)?;
-        Ok(icon)
+    fn load_icon_from_bytes(&self, data: &[u8], scale_factor: f64) -> Result<Icon> {
+        image_utils::window_icon(data, "", scale_factor)
     }
-    fn load_icon(&self, path: &str) -> Result<Icon> {
+    fn load_icon(&self, path: &str, scale_factor: f64) -> Result<Icon> {
         let mut cache = lock!(self.icon_cache)?;
-        let icon = cache.get(path);
+        let key = icon_cache_key(path, scale_factor);
+        let icon = cache.get(&key);
         match icon {
             Some(icon) => Ok(icon.clone()),
             None => {
-                let data = self.load(path)?;
-                if path.ends_with("png") {
-                    let icon = image_utils::png_to_icon(&data)?;
-                    cache.insert(path.to_string(), icon.clone());
-                    Ok(icon)
-                } else {
-                    Err(anyhow::anyhow!("Unsupported icon format."))
-                }
+                let icon = image_utils::window_icon(&self.load(path)?, path, scale_factor)?;
+                cache.insert(key, icon.clone());
+                Ok(icon)
             }
         }
     }
 }
 
+/// Obergrenze für entpackte Einträge im LRU von [`AppResourceManager`].
+const ENTRY_CACHE_SIZE: usize = 16 * 1024 * 1024;
+
 pub struct AppResourceManager {
-    indexes: HashMap<String, (usize, usize)>,
-    data: Vec<u8>,
+    entries: HashMap<String, Entry>,
+    data: BundleData,
+    keys: BundleKeys,
+    /// `warn`: Abweichende Digests werden nur geloggt.
+    warn_only: bool,
+    verified: Mutex<HashSet<String>>,
+    entry_cache: Mutex<LruCache>,
     icon_cache: Mutex<IconCache>,
+    hash_cache: Mutex<HashMap<String, String>>,
 }
 
 impl std::fmt::Debug for AppResourceManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MacOSAppResourceManager")
-            .field("indexes", &self.indexes)
-            .field("data", &"Vec<u8>")
+        f.debug_struct("AppResourceManager")
+            .field("entries", &self.entries)
+            .field("data", &"BundleData")
             .finish()
     }
 }
 
 impl AppResourceManager {
     #[cfg(any(target_os = "macos", target_os = "linux"))]
-    pub fn new() -> Result<Arc<AppResourceManager>> {
+    pub fn new(options: &BundleOptions) -> Result<Arc<AppResourceManager>> {
         let resources_dir = std::env::current_exe()?
             .parent()
             .ok_or(anyhow::anyhow!("Invalid resource directory."))?
             .join("../Resources/");
-        let indexes_data = std::fs::read(resources_dir.join("RESOURCE_INDEXES"))?;
-        let indexes = serde_json::from_slice::<HashMap<String, (usize, usize)>>(&indexes_data)?;
-        let compressed_data = std::fs::read(resources_dir.join("RESOURCE_DATA"))?;
-        let mut decoder = flate2::read::DeflateDecoder::new(&compressed_data[..]);
-        let mut data = Vec::new();
-        decoder.read_to_end(&mut data)?;
-        Ok(arc(AppResourceManager {
-            indexes,
-            data,
-            icon_cache: Mutex::new(HashMap::new()),
-        }))
+        Ok(arc(Self::open(&resources_dir, options)?))
     }
 
     #[cfg(target_os = "windows")]
     #[allow(dead_code)]
-    pub fn new() -> Result<Arc<AppResourceManager>> {
-        use win_utils::load_resource;
-        let indexes_data = load_resource("RESOURCE_INDEXES")?;
-        let indexes = serde_json::from_slice::<HashMap<String, (usize, usize)>>(&indexes_data)?;
-        let compressed_data = load_resource("RESOURCE_DATA")?;
-        let mut decoder = flate2::read::DeflateDecoder::new(&compressed_data[..]);
-        let mut data = Vec::new();
-        decoder.read_to_end(&mut data)?;
-        Ok(arc(AppResourceManager {
-            indexes,
+    pub fn new(options: &BundleOptions) -> Result<Arc<AppResourceManager>> {
+        use win_utils::{load_resource, load_resource_static};
+        let indexes_data = load_resource(packer::INDEXES_FILE)?;
+        let data = BundleData::Static(load_resource_static(packer::DATA_FILE)?);
+        let signature = load_resource(packer::SIGNATURE_FILE).ok();
+        Ok(arc(Self::from_parts(
+            &indexes_data,
             data,
+            signature.as_deref(),
+            options,
+        )?))
+    }
+
+    /// Lädt ein Bundle, wie es [`packer::pack`] in `dir` schreibt; `RESOURCE_DATA` wird gemappt.
+    pub fn open(dir: &Path, options: &BundleOptions) -> Result<AppResourceManager> {
+        let indexes_data = std::fs::read(dir.join(packer::INDEXES_FILE))?;
+        let data = BundleData::map(&dir.join(packer::DATA_FILE))?;
+        let signature = std::fs::read(dir.join(packer::SIGNATURE_FILE)).ok();
+        Self::from_parts(&indexes_data, data, signature.as_deref(), options)
+    }
+
+    fn from_parts(
+        indexes_data: &[u8],
+        data: BundleData,
+        signature: Option<&[u8]>,
+        options: &BundleOptions,
+    ) -> Result<AppResourceManager> {
+        let warn_only = options.on_verify_failure == Some(VerifyFailure::Warn);
+        let keys = BundleKeys::new(options)?;
+        if let Err(err) = keys.verify_signature(indexes_data, signature) {
+            match warn_only {
+                true => log_err!(err),
+                false => return Err(err),
+            }
+        }
+        let (entries, data, data_sha256) = bundle::open(indexes_data, data, &keys)?;
+        // Ein manipuliertes RESOURCE_DATA schon beim Start erkennen, nicht erst beim ersten Zugriff
+        if keys.public_key.is_some() {
+            if let Err(err) = bundle::check_data(data_sha256.as_deref(), &data) {
+                match warn_only {
+                    true => log_err!(err),
+                    false => return Err(err),
+                }
+            }
+        }
+        Ok(AppResourceManager {
+            entries,
+            data,
+            keys,
+            warn_only,
+            verified: Mutex::new(HashSet::new()),
+            entry_cache: Mutex::new(LruCache::new(ENTRY_CACHE_SIZE)),
             icon_cache: Mutex::new(HashMap::new()),
-        }))
+            hash_cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Anzahl der Dateien im Bundle.
+    pub fn file_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Entschlüsselt und entpackt einen Eintrag bei Bedarf und prüft ihn beim ersten Zugriff
+    /// gegen den Digest im Manifest.
+    fn content(&self, path: &str) -> Result<Arc<Vec<u8>>> {
+        let entry = self.entries.get(path).ok_or(anyhow::anyhow!("File not found."))?;
+        let stored = entry
+            .offset
+            .checked_add(entry.length)
+            .and_then(|end| self.data.get(entry.offset..end))
+            .ok_or(anyhow::anyhow!("Bundle entry {} is out of bounds", path))?;
+        let cached = entry.compression != Compression::Store || entry.nonce.is_some();
+        if cached {
+            if let Some(content) = lock!(self.entry_cache)?.get(path) {
+                return Ok(content);
+            }
+        }
+        let content = match entry.nonce {
+            Some(_) => entry
+                .compression
+                .decompress(&self.keys.decrypt(path, entry, stored)?, entry.size)?,
+            None => entry.compression.decompress(stored, entry.size)?,
+        };
+        self.check_digest(path, entry, &content)?;
+        let content = Arc::new(content);
+        if cached {
+            lock!(self.entry_cache)?.insert(path.to_string(), content.clone());
+        }
+        Ok(content)
+    }
+
+    fn check_digest(&self, path: &str, entry: &Entry, content: &[u8]) -> Result<()> {
+        let Some(expected) = &entry.sha256 else {
+            return Ok(());
+        };
+        if lock!(self.verified)?.contains(path) {
+            return Ok(());
+        }
+        if hex::encode(Sha256::digest(content)) != *expected {
+            let err: anyhow::Error = VerificationFailed(format!("{} does not match the manifest", path)).into();
+            match self.warn_only {
+                true => log_err!(err),
+                false => return Err(err),
+            }
+        }
+        lock!(self.verified)?.insert(path.to_string());
+        Ok(())
+    }
+}
+
+/// Bundle der Anwendung unter Beachtung von [`BundleOptions::on_verify_failure`].
+pub fn load_app_bundle(options: Option<&BundleOptions>) -> Result<Arc<dyn ResourceManager>> {
+    let options = options.cloned().unwrap_or_default();
+    match AppResourceManager::new(&options) {
+        std::result::Result::Ok(manager) => Ok(manager as Arc<dyn ResourceManager>),
+        Err(err) if err.is::<VerificationFailed>() && options.on_verify_failure == Some(VerifyFailure::Fallback) => {
+            log_err!(err);
+            let dir = options
+                .fallback_dir
+                .as_deref()
+                .ok_or(anyhow::anyhow!("The fallback policy requires a fallback directory."))?;
+            Ok(FileSystemResource::new(dir)? as Arc<dyn ResourceManager>)
+        }
+        Err(err) => Err(err),
     }
 }
 
 impl ResourceManager for AppResourceManager {
     fn exists(&self, path: &str) -> bool {
-        self.indexes.contains_key(path)
+        self.entries.contains_key(path)
     }
 
-    fn load_tray_icon(&self, path: &str) -> Result<TrayIcon> {
-        let data = self.load(path)?; // Holt die Datei aus self.data basierend auf indexes
-        let decoder = png::Decoder::new(std::io::Cursor::new(&data));
-        let mut reader = decoder.read_info()?;
-        let mut buf = vec![0; reader.output_buffer_size()];
-        let info = reader.next_frame(&mut buf)?;
-        let icon = TrayIcon::from_rgba(buf, info.width, info.height)?;
-        Ok(icon)
+    fn load_tray_icon(&self, path: &str, scale_factor: f64) -> Result<TrayIcon> {
+        image_utils::tray_icon(&self.load(path)?, path, scale_factor)
     }
 
-    fn load_icon_from_bytes(&self, data: &[u8]) -> Result<Icon> {
-        let icon = image_utils::png_to_icon(data)?;
-        Ok(icon)
+    fn load_icon_from_bytes(&self, data: &[u8], scale_factor: f64) -> Result<Icon> {
+        image_utils::window_icon(data, "", scale_factor)
     }
     fn load(&self, path: &str) -> Result<Vec<u8>> {
-        let (offset, length) = *self.indexes.get(path).ok_or(anyhow::anyhow!("File not found."))?;
-        Ok(self.data[offset..(offset + length)].to_vec())
+        Ok(Arc::unwrap_or_clone(self.content(path)?))
+    }
+
+    fn hash(&self, path: &str) -> Result<String> {
+        if let Some(digest) = self.entries.get(path).and_then(|entry| entry.sha256.clone()) {
+            return Ok(digest);
+        }
+        // Das Bundle ändert sich zur Laufzeit nicht, der Hash wird nur einmal berechnet
+        if let Some(hash) = lock!(self.hash_cache)?.get(path) {
+            return Ok(hash.clone());
+        }
+        let hash = hex::encode(Sha256::digest(self.content(path)?.as_slice()));
+        lock!(self.hash_cache)?.insert(path.to_string(), hash.clone());
+        Ok(hash)
     }
 
     fn extract(&self, from: &str, to: &Path) -> Result<()> {
@@ -194,20 +354,37 @@ impl ResourceManager for AppResourceManager {
         Ok(())
     }
 
-    fn load_icon(&self, path: &str) -> Result<Icon> {
+    fn list(&self) -> Result<Vec<String>> {
+        let mut files: Vec<String> = self.entries.keys().cloned().collect();
+        files.sort();
+        Ok(files)
+    }
+
+    fn metadata(&self, path: &str) -> Result<ResourceMetadata> {
+        let entry = self.entries.get(path).ok_or(anyhow::anyhow!("File not found."))?;
+        let compression = match entry.compression {
+            Compression::Store => "store",
+            Compression::Deflate => "deflate",
+            Compression::Zstd => "zstd",
+        };
+        Ok(ResourceMetadata {
+            size: entry.size as u64,
+            compressed_size: Some(entry.length as u64),
+            compression: Some(compression.to_string()),
+            modified: None,
+        })
+    }
+
+    fn load_icon(&self, path: &str, scale_factor: f64) -> Result<Icon> {
         let mut cache = lock!(self.icon_cache)?;
-        let icon = cache.get(path);
+        let key = icon_cache_key(path, scale_factor);
+        let icon = cache.get(&key);
         match icon {
             Some(icon) => Ok(icon.clone()),
             None => {
-                let data = self.load(path)?;
-                if path.ends_with("png") {
-                    let icon = image_utils::png_to_icon(&data)?;
-                    cache.insert(path.to_string(), icon.clone());
-                    Ok(icon)
-                } else {
-                    Err(anyhow::anyhow!("Unsupported icon format."))
-                }
+                let icon = image_utils::window_icon(&self.load(path)?, path, scale_factor)?;
+                cache.insert(key, icon.clone());
+                Ok(icon)
             }
         }
     }
@@ -0,0 +1,195 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use super::{
+    bundle::{self, Compression, Entry, IndexV2, FORMAT_VERSION},
+    AppResourceManager, ResourceManager,
+};
+use crate::options::BundleOptions;
+use anyhow::{anyhow, Result};
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, path::Path};
+
+pub const INDEXES_FILE: &str = "RESOURCE_INDEXES";
+pub const DATA_FILE: &str = "RESOURCE_DATA";
+/// Raw Ed25519 signature over `RESOURCE_INDEXES`.
+pub const SIGNATURE_FILE: &str = "RESOURCE_SIGNATURE";
+
+/// Which files of the source directory end up in the bundle and how they are stored.
+#[derive(Clone, Default)]
+pub struct PackOptions {
+    /// Glob patterns relative to the source directory, empty means everything.
+    pub include: Vec<String>,
+    /// Checked after `include`.
+    pub exclude: Vec<String>,
+    /// Same compression for every file; by default already compressed formats are stored
+    /// and everything else uses zstd.
+    pub compression: Option<Compression>,
+    /// Base64 Ed25519 signing key; writes `RESOURCE_SIGNATURE`.
+    pub signing_key: Option<String>,
+    /// Base64 32 byte key; encrypts every entry with ChaCha20-Poly1305.
+    pub encryption_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PackReport {
+    pub files: usize,
+    pub size: u64,
+    pub compressed_size: u64,
+}
+
+/// Writes a v2 bundle (`RESOURCE_INDEXES` and `RESOURCE_DATA`) for all files below `source` into `target`.
+/// Files are sorted by path, so the same input always produces the same bytes.
+pub fn pack(source: &Path, target: &Path, options: &PackOptions) -> Result<PackReport> {
+    let files = collect_files(source, options)?;
+    let signing_key = match &options.signing_key {
+        Some(key) => Some(bundle::decode_key(key, "signing key")?),
+        None => None,
+    };
+    let encryption_key = match &options.encryption_key {
+        Some(key) => Some(bundle::decode_key(key, "encryption key")?),
+        None => None,
+    };
+
+    let mut entries = BTreeMap::new();
+    let mut data = Vec::new();
+    let mut size = 0u64;
+    for (name, path) in &files {
+        let content = std::fs::read(path)?;
+        let mut compression = options.compression.unwrap_or_else(|| Compression::for_path(name));
+        let mut stored = compression.compress(&content)?;
+        // Lohnt sich die Kompression nicht, wird der Eintrag unverändert gespeichert
+        if compression != Compression::Store && stored.len() >= content.len() {
+            compression = Compression::Store;
+            stored = content.clone();
+        }
+        let mut nonce = None;
+        if let Some(key) = &encryption_key {
+            let (encrypted, entry_nonce) = bundle::encrypt(name, &stored, key)?;
+            stored = encrypted;
+            nonce = Some(entry_nonce);
+        }
+        let entry = Entry {
+            offset: data.len(),
+            length: stored.len(),
+            size: content.len(),
+            compression,
+            sha256: Some(hex::encode(Sha256::digest(&content))),
+            nonce,
+        };
+        entries.insert(name.clone(), entry);
+        data.extend_from_slice(&stored);
+        size += content.len() as u64;
+    }
+    let index = IndexV2 {
+        version: FORMAT_VERSION,
+        encrypted: encryption_key.is_some(),
+        data_sha256: Some(hex::encode(Sha256::digest(&data))),
+        entries,
+    };
+    let indexes_data = serde_json::to_vec(&index)?;
+
+    std::fs::create_dir_all(target)?;
+    std::fs::write(target.join(INDEXES_FILE), &indexes_data)?;
+    std::fs::write(target.join(DATA_FILE), &data)?;
+    let signature_path = target.join(SIGNATURE_FILE);
+    match signing_key {
+        Some(key) => std::fs::write(signature_path, bundle::sign(&indexes_data, &key))?,
+        // Keine veraltete Signatur eines früheren Laufs liegen lassen
+        None if signature_path.exists() => std::fs::remove_file(signature_path)?,
+        None => {}
+    }
+
+    Ok(PackReport {
+        files: files.len(),
+        size,
+        compressed_size: data.len() as u64,
+    })
+}
+
+/// Reads the bundle in `target` back through [`AppResourceManager`] and compares it with `source`,
+/// including signature and digests when the bundle was signed.
+pub fn verify(source: &Path, target: &Path, options: &PackOptions) -> Result<()> {
+    let bundle_options = BundleOptions {
+        public_key: options.signing_key.as_deref().map(bundle::public_key_for).transpose()?,
+        encryption_key: options.encryption_key.clone(),
+        ..Default::default()
+    };
+    let manager = AppResourceManager::open(target, &bundle_options)?;
+    let files = collect_files(source, options)?;
+    if files.len() != manager.file_count() {
+        return Err(anyhow!(
+            "Bundle contains {} files, expected {}",
+            manager.file_count(),
+            files.len()
+        ));
+    }
+    for (name, path) in &files {
+        if manager.load(name)? != std::fs::read(path)? {
+            return Err(anyhow!("Content of {} differs from the bundle", name));
+        }
+    }
+    Ok(())
+}
+
+/// Relative `/`-separated names and absolute paths of all matching files, sorted by name.
+fn collect_files(source: &Path, options: &PackOptions) -> Result<BTreeMap<String, std::path::PathBuf>> {
+    let compile = |patterns: &[String]| -> Result<Vec<glob::Pattern>> {
+        patterns
+            .iter()
+            .map(|pattern| glob::Pattern::new(pattern).map_err(|err| anyhow!("Invalid pattern '{}': {}", pattern, err)))
+            .collect()
+    };
+    let include = compile(&options.include)?;
+    let exclude = compile(&options.exclude)?;
+
+    let mut files = BTreeMap::new();
+    for entry in walkdir::WalkDir::new(source).follow_links(true) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let name = entry.path().strip_prefix(source)?.to_string_lossy().replace('\\', "/");
+        let included = include.is_empty() || include.iter().any(|pattern| pattern.matches(&name));
+        if included && !exclude.iter().any(|pattern| pattern.matches(&name)) {
+            files.insert(name, entry.path().to_path_buf());
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    #[test]
+    fn packing_is_deterministic() {
+        let root = std::env::temp_dir().join(format!("pyframe-packer-{}", std::process::id()));
+        let source = root.join("source");
+        std::fs::create_dir_all(source.join("css")).unwrap();
+        std::fs::write(source.join("index.html"), "<html>".repeat(100)).unwrap();
+        std::fs::write(source.join("css/app.css"), "body {}").unwrap();
+        std::fs::write(source.join("logo.png"), [0u8, 1, 2, 3]).unwrap();
+        let options = PackOptions {
+            signing_key: Some(STANDARD.encode([1u8; 32])),
+            encryption_key: Some(STANDARD.encode([7u8; 32])),
+            ..Default::default()
+        };
+
+        let read = |target: &Path| {
+            [INDEXES_FILE, DATA_FILE, SIGNATURE_FILE].map(|file| std::fs::read(target.join(file)).unwrap())
+        };
+        let first = pack(&source, &root.join("first"), &options).unwrap();
+        pack(&source, &root.join("second"), &options).unwrap();
+        assert_eq!(first.files, 3);
+        assert_eq!(read(&root.join("first")), read(&root.join("second")));
+
+        // Ohne Schlüssel keine Signatur eines früheren Laufs übrig lassen
+        pack(&source, &root.join("first"), &PackOptions::default()).unwrap();
+        assert!(!root.join("first").join(SIGNATURE_FILE).exists());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
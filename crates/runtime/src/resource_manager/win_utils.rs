@@ -45,6 +45,37 @@ pub fn load_resource(name: &str) -> Result<Vec<u8>> {
     }
 }
 
+/// Like [`load_resource`], but borrows the data from the module image instead of copying it.
+/// Resources stay mapped for the lifetime of the process.
+#[allow(dead_code)]
+pub fn load_resource_static(name: &str) -> Result<&'static [u8]> {
+    unsafe {
+        let h_module = GetModuleHandleW(ptr::null());
+        if h_module.is_null() {
+            return Err(anyhow::anyhow!("Failed to get module handle."));
+        }
+
+        let lp_name = to_wstr(name);
+        let h_res_info = FindResourceW(h_module, lp_name.as_ptr(), RT_RCDATA);
+        if h_res_info.is_null() {
+            return Err(anyhow::anyhow!("Failed to find resource."));
+        }
+        let size = SizeofResource(h_module, h_res_info) as usize;
+
+        let h_res_data = LoadResource(h_module, h_res_info);
+        if h_res_data.is_null() {
+            return Err(anyhow::anyhow!("Failed to load resource."));
+        }
+
+        let lp_res_data = LockResource(h_res_data) as *const u8;
+        if lp_res_data.is_null() {
+            return Err(anyhow::anyhow!("Failed to lock resource."));
+        }
+
+        Ok(std::slice::from_raw_parts(lp_res_data, size))
+    }
+}
+
 // The following code is from https://github.com/Brooooooklyn/keyring-node/blob/main/src/entry.rs#L343
 #[allow(dead_code)]
 pub fn to_wstr(s: &str) -> Vec<u16> {
@@ -0,0 +1,173 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use super::{icon_cache_key, image_utils, ResourceManager, ResourceMetadata};
+use crate::{
+    lock,
+    utils::{arc, arc_mut, ArcMut},
+};
+use anyhow::{anyhow, Result};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::{BufReader, Read},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+use tao::window::Icon;
+use tray_icon::Icon as TrayIcon;
+use zip::{CompressionMethod, ZipArchive};
+
+/// Größte Vorab-Reservierung beim Entpacken, die Größe im Zip-Header ist nicht vertrauenswürdig.
+const MAX_PREALLOC: u64 = 1024 * 1024;
+/// Leser, die nach einer Anfrage für die nächste offen bleiben.
+const MAX_IDLE_READERS: usize = 4;
+
+type Archive = ZipArchive<BufReader<File>>;
+
+struct ZipEntry {
+    index: usize,
+    metadata: ResourceMetadata,
+}
+
+/// Web assets from a `.zip` archive; stored and deflate entries are supported.
+pub struct ZipResource {
+    path: PathBuf,
+    entries: BTreeMap<String, ZipEntry>,
+    /// Idle readers; every request takes its own, so inflating one entry does not block others.
+    readers: Mutex<Vec<Archive>>,
+    icon_cache: ArcMut<HashMap<String, Icon>>,
+}
+
+impl std::fmt::Debug for ZipResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZipResource")
+            .field("path", &self.path)
+            .field("entries", &self.entries.len())
+            .finish()
+    }
+}
+
+impl ZipResource {
+    pub fn new(path: &Path) -> Result<Arc<ZipResource>> {
+        let mut archive = open_archive(path)?;
+        let mut entries = BTreeMap::new();
+        for index in 0..archive.len() {
+            let file = archive.by_index_raw(index)?;
+            if file.is_dir() {
+                continue;
+            }
+            let compression = match file.compression() {
+                CompressionMethod::Stored => "store",
+                CompressionMethod::Deflated => "deflate",
+                other => return Err(anyhow!("{} uses unsupported compression {:?}", file.name(), other)),
+            };
+            // Manche Packer schreiben `./` oder Backslashes in die Namen
+            let name = file.name().replace('\\', "/");
+            let name = name.trim_start_matches("./").trim_start_matches('/').to_string();
+            let metadata = ResourceMetadata {
+                size: file.size(),
+                compressed_size: Some(file.compressed_size()),
+                compression: Some(compression.to_string()),
+                modified: file.last_modified().map(unix_time),
+            };
+            entries.insert(name, ZipEntry { index, metadata });
+        }
+        Ok(arc(ZipResource {
+            path: path.to_path_buf(),
+            entries,
+            readers: Mutex::new(vec![archive]),
+            icon_cache: arc_mut(HashMap::new()),
+        }))
+    }
+}
+
+fn open_archive(path: &Path) -> Result<Archive> {
+    Ok(ZipArchive::new(BufReader::new(File::open(path)?))?)
+}
+
+/// Reads at most the size from the header; an entry that inflates to more is rejected.
+fn read_entry(archive: &mut Archive, entry: &ZipEntry) -> Result<Vec<u8>> {
+    let size = entry.metadata.size;
+    let file = archive.by_index(entry.index)?;
+    let mut content = Vec::with_capacity(size.min(MAX_PREALLOC) as usize);
+    // Ein Byte mehr lesen als angegeben, damit zu lange Einträge auffallen
+    file.take(size.saturating_add(1)).read_to_end(&mut content)?;
+    if content.len() as u64 != size {
+        return Err(anyhow!(
+            "Corrupt zip entry: expected {} bytes, got {}",
+            size,
+            content.len()
+        ));
+    }
+    Ok(content)
+}
+
+/// Zip-Zeitstempel haben keine Zeitzone und werden als UTC gelesen.
+fn unix_time(time: zip::DateTime) -> u64 {
+    // days_from_civil nach Howard Hinnant
+    let (month, day) = (time.month() as i64, time.day() as i64);
+    let year = time.year() as i64 - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+    let seconds = days * 86400 + time.hour() as i64 * 3600 + time.minute() as i64 * 60 + time.second() as i64;
+    seconds.max(0) as u64
+}
+
+impl ResourceManager for ZipResource {
+    fn exists(&self, path: &str) -> bool {
+        self.entries.contains_key(path)
+    }
+
+    fn load(&self, path: &str) -> Result<Vec<u8>> {
+        let entry = self.entries.get(path).ok_or(anyhow!("File not found."))?;
+        let idle = lock!(self.readers)?.pop();
+        let mut archive = match idle {
+            Some(archive) => archive,
+            None => open_archive(&self.path)?,
+        };
+        let content = read_entry(&mut archive, entry);
+        let mut readers = lock!(self.readers)?;
+        if readers.len() < MAX_IDLE_READERS {
+            readers.push(archive);
+        }
+        content
+    }
+
+    fn extract(&self, from: &str, to: &Path) -> Result<()> {
+        std::fs::write(to, self.load(from)?)?;
+        Ok(())
+    }
+
+    fn load_icon(&self, path: &str, scale_factor: f64) -> Result<Icon> {
+        let mut cache = lock!(self.icon_cache)?;
+        let key = icon_cache_key(path, scale_factor);
+        if let Some(icon) = cache.get(&key) {
+            return Ok(icon.clone());
+        }
+        let icon = image_utils::window_icon(&self.load(path)?, path, scale_factor)?;
+        cache.insert(key, icon.clone());
+        Ok(icon)
+    }
+
+    fn load_tray_icon(&self, path: &str, scale_factor: f64) -> Result<TrayIcon> {
+        image_utils::tray_icon(&self.load(path)?, path, scale_factor)
+    }
+
+    fn load_icon_from_bytes(&self, data: &[u8], scale_factor: f64) -> Result<Icon> {
+        image_utils::window_icon(data, "", scale_factor)
+    }
+
+    fn list(&self) -> Result<Vec<String>> {
+        Ok(self.entries.keys().cloned().collect())
+    }
+
+    fn metadata(&self, path: &str) -> Result<ResourceMetadata> {
+        let entry = self.entries.get(path).ok_or(anyhow!("File not found."))?;
+        Ok(entry.metadata.clone())
+    }
+}
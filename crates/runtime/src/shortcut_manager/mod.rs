@@ -92,6 +92,13 @@ impl PyFrameShortcutManager {
         Ok(id)
     }
 
+    /// Registers an app-wide hotkey that belongs to no window, returns its `GlobalHotKeyEvent` id.
+    pub fn register_app_hotkey(&mut self, accelerator: &str) -> Result<u32> {
+        let hotkey: HotKey = accelerator.parse()?;
+        self.manager.register(hotkey)?;
+        Ok(hotkey.id())
+    }
+
     pub fn unregister(&mut self, window_id: u8, id: u8) -> Result<()> {
         let (owner_id, _, _) = self
             .shortcuts
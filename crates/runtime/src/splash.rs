@@ -0,0 +1,150 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{options::SplashOptions, unsafe_impl_sync_send, utils::FrameWindowTarget, CoreApplication};
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tao::{
+    dpi::{LogicalPosition, LogicalSize},
+    window::{Window, WindowBuilder, WindowId},
+};
+use wry::{WebView, WebViewBuilder};
+
+const DEFAULT_SIZE: LogicalSize<f64> = LogicalSize::new(480.0, 320.0);
+
+unsafe_impl_sync_send!(SplashScreen);
+/// Undecorated window shown between launch and the first page load of the main window.
+pub struct SplashScreen {
+    _window: Window,
+    webview: WebView,
+    shown_at: Instant,
+    min_display: Duration,
+    closed: Arc<AtomicBool>,
+}
+
+impl SplashScreen {
+    pub fn open(app: &Arc<CoreApplication>, target: &FrameWindowTarget, options: &SplashOptions) -> Result<Self> {
+        let size = options.size.unwrap_or(DEFAULT_SIZE);
+        let transparent = options.transparent.unwrap_or(false);
+
+        let mut builder = WindowBuilder::new()
+            .with_title(app.launch_info.options.name.clone())
+            .with_inner_size(size)
+            .with_decorations(false)
+            .with_resizable(false)
+            .with_always_on_top(true)
+            .with_transparent(transparent)
+            .with_window_icon(Some(app.resource().load_icon_from_bytes(
+                crate::assets::DEFAULT_LOGO,
+                crate::utils::primary_scale_factor(target),
+            )?));
+
+        // Auf dem Hauptbildschirm zentrieren
+        if let Some(monitor) = target.primary_monitor() {
+            let scale = monitor.scale_factor();
+            let screen = monitor.size().to_logical::<f64>(scale);
+            let origin = monitor.position().to_logical::<f64>(scale);
+            builder = builder.with_position(LogicalPosition::new(
+                origin.x + (screen.width - size.width) / 2.0,
+                origin.y + (screen.height - size.height) / 2.0,
+            ));
+        }
+
+        let window = builder.build(target)?;
+        let webview = WebViewBuilder::new()
+            .with_initialization_script(crate::assets::SPLASH_SCRIPT)
+            .with_transparent(transparent)
+            .with_html(Self::content(app, options)?);
+
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "ios", target_os = "android"))]
+        let webview = webview.build(&window)?;
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "ios", target_os = "android")))]
+        let webview = {
+            use tao::platform::unix::WindowExtUnix;
+            use wry::WebViewBuilderExtUnix;
+            let vbox = window.default_vbox().unwrap();
+            webview.build_gtk(vbox)?
+        };
+
+        Ok(Self {
+            _window: window,
+            webview,
+            shown_at: Instant::now(),
+            min_display: Duration::from_millis(options.min_display_ms.unwrap_or(0)),
+            closed: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    fn content(app: &Arc<CoreApplication>, options: &SplashOptions) -> Result<String> {
+        if let Some(path) = &options.html {
+            return Ok(String::from_utf8(app.resource().load(path)?)?);
+        }
+
+        let image = match &options.image {
+            Some(path) => {
+                let data = app.resource().load(path)?;
+                let mime = mime_guess::from_path(path).first_or_octet_stream();
+                format!(
+                    r#"<img src="data:{};base64,{}" alt="">"#,
+                    mime.essence_str(),
+                    STANDARD.encode(data)
+                )
+            }
+            None => String::new(),
+        };
+
+        Ok(format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<style>
+  html, body {{ margin: 0; height: 100%; overflow: hidden; background: transparent; font-family: sans-serif; }}
+  body {{ display: flex; flex-direction: column; align-items: center; justify-content: center; }}
+  img {{ max-width: 100%; max-height: 85%; object-fit: contain; }}
+  #pyframe-splash-progress {{ margin-top: 12px; font-size: 13px; color: #666; }}
+</style>
+</head>
+<body>
+{image}
+<div id="pyframe-splash-progress"></div>
+</body>
+</html>"#
+        ))
+    }
+
+    pub fn set_progress(&self, message: &str) -> Result<()> {
+        let message = serde_json::to_string(message)?;
+        self.webview
+            .evaluate_script(&format!("PyFrameSplash.setProgress({message})"))?;
+        Ok(())
+    }
+
+    pub fn window_id(&self) -> WindowId {
+        self._window.id()
+    }
+
+    /// Time left until the minimum display time is reached.
+    pub fn remaining(&self) -> Duration {
+        self.min_display.saturating_sub(self.shown_at.elapsed())
+    }
+
+    /// Flag that turns `true` once the splash has been closed.
+    pub fn closed(&self) -> Arc<AtomicBool> {
+        self.closed.clone()
+    }
+}
+
+impl Drop for SplashScreen {
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+}
@@ -24,18 +24,43 @@ pub type FrameEventLoopBuilder = EventLoopBuilder<UserEvent>;
 pub type FrameEventLoopProxy = EventLoopProxy<UserEvent>;
 pub type FrameWindowTarget = EventLoopWindowTarget<UserEvent>;
 
+/// Scale factor of the primary monitor, for things created before their window exists.
+pub fn primary_scale_factor(target: &FrameWindowTarget) -> f64 {
+    target
+        .primary_monitor()
+        .map(|monitor| monitor.scale_factor())
+        .unwrap_or(1.0)
+}
+
 pub type FrameCallback = Pin<Box<dyn Fn(&FrameWindowTarget, &mut ControlFlow) -> Result<()> + Send>>;
 pub struct FrameEvent(FrameCallback);
 
 pub enum UserEvent {
     FrameEvent(FrameEvent),
     MenuEvent(muda::MenuEvent),
+    HotKeyEvent(global_hotkey::GlobalHotKeyEvent),
+    TrayIconEvent(tray_icon::TrayIconEvent),
     CloseWindow,
     Minimize(WindowId),
     Maximize(WindowId),
     DragWindow(WindowId),
     MouseDown(WindowId, i32, i32),
     MouseMove(WindowId, i32, i32),
+    BackendReady(Option<SocketSettings>),
+    BackendFailed(String),
+    SplashProgress(String),
+    CloseSplash,
+    PageLoaded(WindowId),
+    CloseRequested(WindowId),
+    RequestQuit(i32),
+    /// All `app.beforeQuit` replies are in or the wait timed out.
+    FinishQuit(u32),
+    Exit(i32),
+    ShutdownComplete(i32),
+    WindowReady(WindowId),
+    ShowMainWindow,
+    ResourcesChanged(Vec<String>),
+    LocaleChanged,
 }
 
 impl Debug for FrameEvent {
@@ -244,6 +269,12 @@ pub fn get_json_sync(url: &str) -> anyhow::Result<serde_json::Value> {
     Ok(response)
 }
 
+pub fn post_json_sync(url: &str, body: &serde_json::Value) -> anyhow::Result<()> {
+    let client = reqwest::blocking::Client::new();
+    client.post(url).json(body).send()?.error_for_status()?;
+    Ok(())
+}
+
 #[allow(dead_code)]
 pub fn fetch_json_struct<T: serde::de::DeserializeOwned>(url: &str) -> anyhow::Result<T> {
     let client = reqwest::blocking::Client::new();
@@ -289,21 +320,26 @@ pub fn read_json_from_stdin() -> anyhow::Result<Value> {
     Ok(data)
 }
 
-#[cfg(target_os = "windows")]
+/// wry liefert Custom-Protocols unter Windows und Android als `http://<protocol>.<host>` aus.
+#[cfg(any(target_os = "windows", target_os = "android"))]
 pub fn make_base_url(protocol: &str, host: &str) -> String {
     format!("http://{}.{}", protocol, host)
 }
 
-#[cfg(any(target_os = "macos", target_os = "linux"))]
+#[cfg(not(any(target_os = "windows", target_os = "android")))]
 pub fn make_base_url(protocol: &str, host: &str) -> String {
     format!("{}://{}", protocol, host)
 }
 
+/// Origin of `url` as `scheme://host[:port]`; the port is left out when it is the scheme's default.
 pub fn get_host_from_url(url: &str) -> Option<String> {
     let url = url::Url::parse(url).ok()?;
     let scheme = url.scheme();
     let host = url.host_str()?;
-    Some(format!("{}://{}", scheme, host))
+    match url.port() {
+        Some(port) => Some(format!("{}://{}:{}", scheme, host, port)),
+        None => Some(format!("{}://{}", scheme, host)),
+    }
 }
 
 /*
@@ -440,7 +476,7 @@ pub fn menu_provider(app: &Arc<CoreApplication>, window: tao::window::Window) ->
             if let Some(menu_frame) = &config {
                 if menu_frame.has_menu_item() {
                     let mut menu_sys_guard = app.menu()?;
-                    menu_sys_guard.register_menu_items(menu_frame.clone())?;
+                    menu_sys_guard.register_menu_items(menu_frame.clone(), window.scale_factor())?;
                     let menu_bar = menu_sys_guard.get_menu_manager()?;
 
                     #[cfg(target_os = "windows")]
@@ -467,7 +503,7 @@ pub fn menu_provider(app: &Arc<CoreApplication>, window: tao::window::Window) ->
                 if menu_frame.has_menu_item() {
                     let mut menu_sys_guard = app.menu()?;
                     {
-                        menu_sys_guard.register_menu_items(menu_frame.clone())?;
+                        menu_sys_guard.register_menu_items(menu_frame.clone(), window.scale_factor())?;
                     }
 
                     //let _menu_bar = menu_sys_guard.get_menu_manager()?;
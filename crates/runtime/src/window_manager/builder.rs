@@ -7,14 +7,15 @@ use std::sync::Arc;
 //use serde_json::json;
 
 use crate::{
-    log_if_err,
-    options::window::WindowConfig,
+    api_manager::ApiRequest,
+    log_err, log_if_err,
+    options::window::{FileDropMode, WindowConfig},
     set_property, set_property_some,
-    utils::{FrameWindowTarget, UserEvent},
+    utils::{FrameEvent, FrameWindowTarget, UserEvent},
     CoreApplication,
 };
 
-use super::WindowManager;
+use super::{ipc::WindowCommand, new_window::NewWindowHandler, WindowManager};
 
 pub struct FrameBuilder;
 
@@ -34,11 +35,14 @@ impl FrameBuilder {
             options.window_title.clone().unwrap_or("PyFrame".to_string())
         );
         set_property_some!(builder, with_always_on_bottom, options.window_always_on_bottom);
+        let scale_factor = crate::utils::primary_scale_factor(target);
         if let Some(icon_path) = &options.window_window_icon {
-            let icon = _app.resource().load_icon(icon_path)?;
+            let icon = _app.resource().load_icon(icon_path, scale_factor)?;
             set_property!(builder, with_window_icon, Some(icon));
         } else {
-            let icon = _app.resource().load_icon_from_bytes(crate::assets::DEFAULT_LOGO)?;
+            let icon = _app
+                .resource()
+                .load_icon_from_bytes(crate::assets::DEFAULT_LOGO, scale_factor)?;
             set_property!(builder, with_window_icon, Some(icon));
         }
         set_property_some!(builder, with_always_on_top, options.window_always_on_top);
@@ -83,6 +87,10 @@ impl FrameBuilder {
         set_property_some!(builder, with_resizable, options.window_resizable);
         set_property_some!(builder, with_transparent, options.window_transparent);
         set_property_some!(builder, with_visible, options.window_visible);
+        if options.pending_show().is_some() {
+            // Versteckt erstellen, FrameWindow zeigt es nach dem Laden an
+            set_property!(builder, with_visible, false);
+        }
         set_property_some!(
             builder,
             with_visible_on_all_workspaces,
@@ -146,7 +154,7 @@ impl FrameBuilder {
             }
 
             if let Some(icon_path) = &windows_extra.taskbar_icon {
-                let icon = _app.resource().load_icon(icon_path)?;
+                let icon = _app.resource().load_icon(icon_path, scale_factor)?;
                 set_property!(builder, with_taskbar_icon, Some(icon));
             }
 
@@ -180,21 +188,38 @@ impl FrameBuilder {
 
         let app_settings = cloned_app.launch_info.options.clone();
         let internal_api = app_settings.internal_api;
-        let sock = cloned_app.launch_info.socket_settings.clone();
+        let sock = cloned_app.socket_settings()?.clone();
         let external_proto = cloned_app.launch_info.options.web_proto.clone();
 
-        let mut builder = wry::WebViewBuilder::new().with_initialization_script(crate::assets::INITIALIZE_SCRIPT);
+        // Fremde Seiten (`window.open` mit der Aktion `window`) bekommen weder Init-Skript noch API
+        let trusted = !crate::window_manager::protocol::is_external_entry(_app, options);
+
+        let mut builder = wry::WebViewBuilder::new();
+        if options.window_decorations == Some(false) {
+            // Vor dem Init-Skript setzen, damit das Frameless-Kit die Resize-Ränder aktiviert
+            builder = builder.with_initialization_script(format!(
+                "window.__PYFRAME_FRAMELESS__ = {{ resizeInset: {} }};",
+                options.resize_inset()
+            ));
+        }
+        if trusted {
+            builder = builder.with_initialization_script(crate::assets::INITIALIZE_SCRIPT);
+        }
 
         wry::WebViewBuilder::with_web_context(_web_context);
 
-        if internal_api.unwrap_or(false) {
-            builder = builder
-                .with_initialization_script(crate::assets::SOCKETIO_SCRIPT)
-                .with_initialization_script(crate::assets::INITIALIZE_SCRIPT)
-                .with_initialization_script(crate::utils::generate_socketio_js(sock, app_settings))
-                .with_initialization_script(crate::assets::INITIALIZEPY_SCRIPT);
-        } else {
-            builder = builder.with_initialization_script(crate::assets::INITIALIZE_SCRIPT);
+        match (trusted, internal_api.unwrap_or(false)) {
+            (false, _) => {}
+            (true, true) => {
+                builder = builder
+                    .with_initialization_script(crate::assets::SOCKETIO_SCRIPT)
+                    .with_initialization_script(crate::assets::INITIALIZE_SCRIPT)
+                    .with_initialization_script(crate::utils::generate_socketio_js(sock, app_settings))
+                    .with_initialization_script(crate::assets::INITIALIZEPY_SCRIPT);
+            }
+            (true, false) => {
+                builder = builder.with_initialization_script(crate::assets::INITIALIZE_SCRIPT);
+            }
         }
 
         set_property_some!(builder, with_accept_first_mouse, options.webview_accept_first_mouse);
@@ -205,6 +230,10 @@ impl FrameBuilder {
             options.webview_back_forward_navigation_gestures
         );
         set_property_some!(builder, with_background_color, options.webview_background_color);
+        if options.pending_show().is_some() && options.webview_background_color.is_none() {
+            // Ohne eigene Webview-Farbe die Fensterfarbe übernehmen, damit beim Anzeigen nichts aufblitzt
+            set_property_some!(builder, with_background_color, options.window_background_color);
+        }
         set_property_some!(builder, with_clipboard, options.webview_clipboard);
         set_property_some!(builder, with_devtools, options.webview_devtools);
         set_property_some!(builder, with_focused, options.window_focused);
@@ -227,58 +256,114 @@ impl FrameBuilder {
             set_property!(builder, with_background_throttling, wry_policy);
         }
 
+        let win_id = target.id();
+        let new_window = NewWindowHandler::new(cloned_app.clone(), options, win_id);
+
         builder = match external_proto {
             Some(proto) if proto.starts_with("https") || proto.starts_with("http") => {
-                crate::window_manager::protocol::build_full_url(builder, cloned_app.clone())?
+                crate::window_manager::protocol::build_full_url(
+                    builder,
+                    cloned_app.clone(),
+                    options,
+                    new_window.clone(),
+                )?
             }
-            _ => crate::window_manager::protocol::render_web_protocol(cloned_app.clone(), builder)?,
+            _ => crate::window_manager::protocol::render_web_protocol(
+                cloned_app.clone(),
+                options,
+                new_window.clone(),
+                builder,
+            )?,
         };
-        let win_id = target.id();
+        builder = builder.with_new_window_req_handler(move |url| {
+            new_window.handle(url, "newWindow");
+            false
+        });
+
+        let page_load_app = cloned_app.clone();
+        builder = builder.with_on_page_load_handler(move |event, _url| {
+            if let wry::PageLoadEvent::Finished = event {
+                let _ = page_load_app.proxy.send_event(UserEvent::PageLoaded(win_id));
+            }
+        });
+
+        let drop_app = cloned_app.clone();
+        let block_default = options.window_file_drop.unwrap_or_default() == FileDropMode::Native;
+        builder = builder.with_drag_drop_handler(move |event| {
+            let app = drop_app.clone();
+            log_if_err!(drop_app
+                .proxy
+                .send_event(UserEvent::FrameEvent(FrameEvent::new(move |_, _| {
+                    let window = app.window()?.get_window_inner(win_id)?;
+                    let scale_factor = window.scale_factor();
+                    let position =
+                        |(x, y): (i32, i32)| tao::dpi::PhysicalPosition::new(x, y).to_logical::<f64>(scale_factor);
+                    match &event {
+                        wry::DragDropEvent::Enter { paths, position: pos } => window.emit_event(
+                            "window.fileDropEnter",
+                            serde_json::json!({ "paths": paths, "position": position(*pos) }),
+                        ),
+                        wry::DragDropEvent::Over { position: pos } => window.emit_subscribed_event(
+                            "window.fileDropOver",
+                            serde_json::json!({ "position": position(*pos) }),
+                        ),
+                        wry::DragDropEvent::Drop { paths, position: pos } => window.emit_event(
+                            "window.fileDrop",
+                            serde_json::json!({ "paths": paths, "position": position(*pos) }),
+                        ),
+                        wry::DragDropEvent::Leave => window.emit_event("window.fileDropLeave", serde_json::json!(null)),
+                        _ => Ok(()),
+                    }
+                })))
+                .map_err(|_| anyhow::anyhow!("Failed to send event")));
+            block_default
+        });
 
         let ipc_app = _app.clone();
 
         set_property!(builder, with_ipc_handler, {
             let _ipc_app = cloned_app.clone();
             move |request: wry::http::Request<String>| {
+                // Nur Seiten der App dürfen die API aufrufen, auch nach einer Navigation
+                let origin = request.uri().to_string();
+                if !crate::window_manager::protocol::is_app_url(&ipc_app, &origin) {
+                    log_err!(format!("Rejected IPC message from {}", origin));
+                    return;
+                }
                 let window_result = ipc_app.window().and_then(|w| w.get_window_inner(win_id));
                 let request_str = request.body();
-                let mut req = request_str.split([':', ',']);
-                match req.next().unwrap() {
-                    "minimize" => {
-                        let _ = _ipc_app.proxy.send_event(UserEvent::Minimize(win_id));
-                    }
-                    "maximize" => {
-                        let _ = _ipc_app.proxy.send_event(UserEvent::Maximize(win_id));
+
+                let api_request = match serde_json::from_str::<ApiRequest>(request_str) {
+                    Ok(api_request) => api_request,
+                    Err(err) => {
+                        log_err!(format!("Rejected malformed IPC message {:?}: {}", request_str, err));
+                        if let Ok(window) = &window_result {
+                            log_if_err!(window.send_ipc_callback(serde_json::json!({
+                                "ipc.error": err.to_string(),
+                            })));
+                        }
+                        return;
                     }
-                    "drag_window" => {
-                        println!("Drag");
-                        let _ = _ipc_app.proxy.send_event(UserEvent::DragWindow(win_id));
+                };
+
+                match (WindowCommand::parse(&api_request), window_result) {
+                    (Ok(Some(command)), _) => {
+                        let _ = _ipc_app.proxy.send_event(command.into_event(win_id));
                     }
-                    "close" => {
-                        let _ = _ipc_app.proxy.send_event(UserEvent::CloseWindow);
+                    (Ok(None), Ok(window)) => {
+                        if let Err(err) = ipc_app.api().and_then(|w| w.call(&window, request_str.to_string())) {
+                            log_if_err!(window.send_ipc_callback(serde_json::json!({
+                                "ipc.error": err.to_string(),
+                            })));
+                        }
                     }
-                    "mousedown" => {
-                        let x = req.next().unwrap().parse().unwrap();
-                        let y = req.next().unwrap().parse().unwrap();
-                        let _ = _ipc_app.proxy.send_event(UserEvent::MouseDown(win_id, x, y));
+                    (Err(err), Ok(window)) => {
+                        log_err!(format!("Rejected IPC message {:?}: {}", request_str, err));
+                        log_if_err!(window.send_ipc_callback(api_request.err(-1, err.to_string())));
                     }
-                    "mousemove" => {
-                        let x = req.next().unwrap().parse().unwrap();
-                        let y = req.next().unwrap().parse().unwrap();
-                        let _ = _ipc_app.proxy.send_event(UserEvent::MouseMove(win_id, x, y));
+                    (_, Err(err)) => {
+                        println!("WARN: Window for id {:?} not found: {:?}", win_id, err);
                     }
-                    _ => match window_result {
-                        Ok(window) => {
-                            if let Err(err) = ipc_app.api().and_then(|w| w.call(&window, request_str.to_string())) {
-                                log_if_err!(window.send_ipc_callback(serde_json::json!({
-                                    "ipc.error": err.to_string(),
-                                })));
-                            }
-                        }
-                        Err(err) => {
-                            println!("WARN: Window for id {:?} not found: {:?}", win_id, err);
-                        }
-                    },
                 }
             }
         });
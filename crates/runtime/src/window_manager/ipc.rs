@@ -0,0 +1,72 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{api_manager::ApiRequest, utils::UserEvent};
+use anyhow::{anyhow, Result};
+use tao::window::WindowId;
+
+/// Window-control commands sent by the init script as `[id, "frame.<command>", args]`.
+/// They use the `ApiRequest` envelope but are handled on the event loop instead of the API manager.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowCommand {
+    Minimize,
+    Maximize,
+    DragWindow,
+    Close,
+    CloseRequested,
+    Ready,
+    MouseDown(i32, i32),
+    MouseMove(i32, i32),
+}
+
+impl WindowCommand {
+    pub const PREFIX: &'static str = "frame.";
+
+    /// `Ok(None)` if the request is a regular API call.
+    pub fn parse(request: &ApiRequest) -> Result<Option<Self>> {
+        let Some(name) = request.1.strip_prefix(Self::PREFIX) else {
+            return Ok(None);
+        };
+
+        let command = match name {
+            "minimize" => Self::Minimize,
+            "maximize" => Self::Maximize,
+            "dragWindow" => Self::DragWindow,
+            "close" => Self::Close,
+            "closeRequested" => Self::CloseRequested,
+            "ready" => Self::Ready,
+            "mouseDown" => {
+                let (x, y) = Self::position(request)?;
+                Self::MouseDown(x, y)
+            }
+            "mouseMove" => {
+                let (x, y) = Self::position(request)?;
+                Self::MouseMove(x, y)
+            }
+            _ => return Err(anyhow!("Unknown window command '{}'", request.1)),
+        };
+        Ok(Some(command))
+    }
+
+    fn position(request: &ApiRequest) -> Result<(i32, i32)> {
+        let (x, y) = request
+            .args()
+            .get::<(f64, f64)>()
+            .map_err(|err| anyhow!("Invalid arguments for '{}': {}", request.1, err))?;
+        Ok((x.round() as i32, y.round() as i32))
+    }
+
+    pub fn into_event(self, window_id: WindowId) -> UserEvent {
+        match self {
+            Self::Minimize => UserEvent::Minimize(window_id),
+            Self::Maximize => UserEvent::Maximize(window_id),
+            Self::DragWindow => UserEvent::DragWindow(window_id),
+            Self::Close => UserEvent::CloseWindow,
+            Self::CloseRequested => UserEvent::CloseRequested(window_id),
+            Self::Ready => UserEvent::WindowReady(window_id),
+            Self::MouseDown(x, y) => UserEvent::MouseDown(window_id, x, y),
+            Self::MouseMove(x, y) => UserEvent::MouseMove(window_id, x, y),
+        }
+    }
+}
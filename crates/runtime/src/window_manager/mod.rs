@@ -3,7 +3,11 @@
 // SPDX-License-Identifier: MIT
 
 pub mod builder;
+pub mod ipc;
+pub mod new_window;
 pub mod protocol;
+pub mod range;
+pub mod security;
 pub mod window;
 use crate::{
     //lock,
@@ -47,6 +51,19 @@ impl WindowManager {
     #[allow(dead_code)]
     pub fn open_window(&mut self, target: &FrameWindowTarget, options: &WindowConfig) -> Result<Arc<FrameWindow>> {
         let id = self.id_counter.next(&self.windows)?;
+        self.open_window_with_id(target, id, options)
+    }
+
+    /// Opens a window under a fixed id, used to reopen the main window as id 0.
+    pub fn open_window_with_id(
+        &mut self,
+        target: &FrameWindowTarget,
+        id: u8,
+        options: &WindowConfig,
+    ) -> Result<Arc<FrameWindow>> {
+        if self.windows.contains_key(&id) {
+            return Err(anyhow!("Window {id} already open"));
+        }
 
         let frame = self.app.clone().ok_or(anyhow!("Frame not found"))?;
 
@@ -97,7 +114,24 @@ impl WindowManager {
 
         let _frame = self.app.clone().ok_or(anyhow!("Frame not found"))?;
         _frame.shortcut()?.unregister_all(id)?;
+        _frame.fs_watch()?.unwatch_all(id);
         // frame.tray()?.destroy_all(id)?;
+
+        // Das Fenster selbst kann nichts mehr empfangen, die übrigen Fenster und Python darüber informieren
+        for remaining in self.windows.values() {
+            remaining.send_ipc_event("window.destroyed", serde_json::json!({ "id": id }))?;
+        }
+        match self.windows.values().next() {
+            Some(remaining) => remaining.post_message(FrameWindow::python_event(
+                id,
+                "window.destroyed",
+                serde_json::Value::Null,
+            ))?,
+            None => {
+                _frame.post_backend_event(Some(id), "window.destroyed", serde_json::Value::Null)?;
+                _frame.post_backend_event(None, "app.allWindowsClosed", serde_json::Value::Null)?;
+            }
+        }
         Ok(())
     }
     #[allow(dead_code)]
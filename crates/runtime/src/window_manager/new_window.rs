@@ -0,0 +1,111 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    log_err, log_if_err,
+    options::{window::WindowConfig, NewWindowAction, NewWindowOptions},
+    utils::{FrameEvent, UserEvent},
+    CoreApplication,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tao::window::WindowId;
+
+/// Compiled form of [`NewWindowOptions`].
+pub struct NewWindowPolicy {
+    default_action: NewWindowAction,
+    rules: Vec<(glob::Pattern, NewWindowAction)>,
+    window: Option<WindowConfig>,
+}
+
+impl NewWindowPolicy {
+    pub fn new(options: Option<&NewWindowOptions>) -> Self {
+        let options = options.cloned().unwrap_or_default();
+        let rules = options
+            .rules
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|rule| match glob::Pattern::new(&rule.pattern) {
+                Ok(pattern) => Some((pattern, rule.action)),
+                Err(err) => {
+                    log_err!(format!("Invalid new window pattern '{}': {}", rule.pattern, err));
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            default_action: options.default_action.unwrap_or_default(),
+            rules,
+            window: options.window,
+        }
+    }
+
+    pub fn action_for(&self, url: &str) -> NewWindowAction {
+        self.rules
+            .iter()
+            .find(|(pattern, _)| pattern.matches(url))
+            .map(|(_, action)| *action)
+            .unwrap_or(self.default_action)
+    }
+}
+
+/// Applies the [`NewWindowPolicy`] to the URLs one webview tries to leave for.
+pub struct NewWindowHandler {
+    app: Arc<CoreApplication>,
+    policy: NewWindowPolicy,
+    opener: WindowConfig,
+    window_id: WindowId,
+}
+
+impl NewWindowHandler {
+    pub fn new(app: Arc<CoreApplication>, opener: &WindowConfig, window_id: WindowId) -> Arc<Self> {
+        let policy = NewWindowPolicy::new(app.launch_info.options.new_window.as_ref());
+        Arc::new(Self {
+            app,
+            policy,
+            opener: opener.clone(),
+            window_id,
+        })
+    }
+
+    /// Handles `url` and reports the decision as `window.newWindowRequested`.
+    /// `source` is either `newWindow` or `navigation`.
+    pub fn handle(&self, url: String, source: &str) -> NewWindowAction {
+        let action = self.policy.action_for(&url);
+
+        match action {
+            NewWindowAction::Window => {
+                let mut config = self.policy.window.clone().unwrap_or_else(|| self.opener.clone());
+                config.entry = Some(url.clone());
+                let app = self.app.clone();
+                log_if_err!(self
+                    .app
+                    .proxy
+                    .send_event(UserEvent::FrameEvent(FrameEvent::new(move |target, _| {
+                        app.window()?.open_window(target, &config)?;
+                        Ok(())
+                    })))
+                    .map_err(|_| anyhow::anyhow!("Failed to send event")));
+            }
+            NewWindowAction::Browser => {
+                log_if_err!(opener::open_browser(&url));
+            }
+            NewWindowAction::Deny => {}
+        }
+
+        if let Ok(window) = self.app.window().and_then(|w| w.get_window_inner(self.window_id)) {
+            log_if_err!(window.send_ipc_event(
+                "window.newWindowRequested",
+                json!({
+                    "url": url,
+                    "action": action,
+                    "source": source,
+                }),
+            ));
+        }
+
+        action
+    }
+}
@@ -3,9 +3,23 @@
 // SPDX-License-Identifier: MIT
 
 use serde_json::Value;
-use wry::http::Request;
+use std::{
+    borrow::Cow,
+    io::{Read, Seek, SeekFrom},
+    sync::Arc,
+};
+use wry::http::{header, Request, Response};
 
-use crate::utils::{get_host_from_url, make_base_url, url_join};
+use super::new_window::NewWindowHandler;
+use super::range::RangeRequest;
+use super::security::SecurityPolicy;
+use crate::fs_scope::{FsScope, ScopeDenied};
+use crate::lock;
+use crate::lock_force;
+use crate::log_if_err;
+use crate::options::{window::WindowConfig, ServeOptions};
+use crate::resource_manager::ResourceManager;
+use crate::utils::{get_host_from_url, make_base_url, url_join, ArcMut};
 
 pub fn ipc_listener(app: &std::sync::Arc<crate::CoreApplication>) -> impl Fn(Request<String>) + 'static {
     let _cloned_app = app.clone();
@@ -51,70 +65,351 @@ fn parse_py_response(data: &str) -> anyhow::Result<Value> {
     }
 }
 
+/// Größter Ausschnitt, der für offene Ranges (`bytes=0-`) aus dem Dateisystem gelesen wird.
+const MAX_RANGE_CHUNK: u64 = 1024 * 1024;
+
+/// Entry des Fensters, ansonsten der Entry des Hauptfensters.
+fn window_entry(app: &crate::CoreApplication, options: &WindowConfig) -> Option<String> {
+    options.entry.clone().or(app.launch_info.options.window.entry.clone())
+}
+
+fn is_absolute_url(entry: &str) -> bool {
+    url::Url::parse(entry).is_ok_and(|url| url.has_host())
+}
+
+/// Server, den ein http(s)-`web_proto` ohne `debug_entry` lädt.
+const DEFAULT_SERVER_URL: &str = "http://localhost:8080";
+
+/// Origins the app's own pages come from: the custom protocol, `debug_entry` and, with an
+/// http(s) `web_proto`, its server. Only these get the PyFrame API.
+pub fn app_origins(app: &crate::CoreApplication) -> Vec<String> {
+    let options = &app.launch_info.options;
+    let http_server = options
+        .web_proto
+        .as_deref()
+        .is_some_and(|proto| proto.starts_with("http"));
+    let mut urls = vec![make_base_url("pyframe", &app.launch_info.id_name)];
+    match &options.debug_entry {
+        Some(debug_entry) => urls.push(debug_entry.clone()),
+        None if http_server => urls.push(DEFAULT_SERVER_URL.to_string()),
+        None => {}
+    }
+    urls.iter().map(String::as_str).filter_map(get_host_from_url).collect()
+}
+
+pub fn is_app_url(app: &crate::CoreApplication, url: &str) -> bool {
+    get_host_from_url(url).is_some_and(|origin| app_origins(app).contains(&origin))
+}
+
+/// Whether the window loads a foreign site, which gets neither the init script nor IPC.
+pub fn is_external_entry(app: &crate::CoreApplication, options: &WindowConfig) -> bool {
+    window_entry(app, options).is_some_and(|entry| is_absolute_url(&entry) && !is_app_url(app, &entry))
+}
+
 #[allow(clippy::op_ref)]
 pub fn render_web_protocol(
     app: std::sync::Arc<crate::CoreApplication>,
+    options: &WindowConfig,
+    new_window: Arc<NewWindowHandler>,
     builder: wry::WebViewBuilder,
 ) -> anyhow::Result<wry::WebViewBuilder> {
     let id_name = app.launch_info.id_name.clone();
-    let entry = app.launch_info.options.window.entry.clone();
+    let entry = window_entry(&app, options);
     let protocol = "pyframe";
     let debug_entry = app.launch_info.options.debug_entry.clone();
     let base_url = debug_entry.unwrap_or(make_base_url(protocol, &id_name));
-    let entry_url = url_join(&base_url, &entry.clone().unwrap_or_default());
+    let entry_url = match &entry {
+        Some(entry) if is_absolute_url(entry) => entry.clone(),
+        _ => url_join(&base_url, &entry.clone().unwrap_or_default()),
+    };
 
     let prefix = get_host_from_url(&entry_url).unwrap_or(base_url.to_owned());
-    let custom_protocol_app = app.clone();
+    let resource = app.resource();
+    let fs_scope = app._fs_scope.clone();
+    // Nie aus dem Entry ableiten, der kann eine fremde Seite sein
+    let page_origin = get_host_from_url(&base_url).unwrap_or(base_url.clone());
+    let security = Arc::new(SecurityPolicy::new(&app.launch_info.options));
+    let serve = app.launch_info.options.serve.clone().unwrap_or_default();
+    let thread_pool = app.thread_pool();
     let builder = builder
-        .with_navigation_handler(move |url| url.starts_with(&prefix))
-        .with_custom_protocol(protocol.to_string(), move |_, request| {
-            let hostname = request.uri().host().unwrap_or(&id_name);
+        .with_initialization_script(security.nonce_script())
+        .with_navigation_handler(move |url| {
+            if url.starts_with(&prefix) {
+                return true;
+            }
+            new_window.handle(url, "navigation");
+            false
+        })
+        .with_asynchronous_custom_protocol(protocol.to_string(), move |_, request, responder| {
+            // Dateien werden im Thread-Pool gelesen, nicht im Event-Loop-Thread und ohne eigenen Thread je Anfrage
+            let sources = ProtocolSources {
+                resource: resource.clone(),
+                fs_scope: fs_scope.clone(),
+                id_name: id_name.clone(),
+                page_origin: page_origin.clone(),
+                security: security.clone(),
+                serve: serve.clone(),
+            };
+            log_if_err!(lock_force!(thread_pool).run(move || {
+                responder.respond(protocol_response(&sources, &request));
+                Ok(())
+            }));
+        })
+        .with_url(&entry_url);
+    Ok(builder)
+}
 
-            let mut path = request.uri().path().to_string();
+/// Everything a protocol request needs, moved to the worker thread.
+struct ProtocolSources {
+    resource: Arc<dyn ResourceManager>,
+    fs_scope: ArcMut<FsScope>,
+    id_name: String,
+    /// Origin of the app page, the only one allowed to read from the `filesystem` host.
+    page_origin: String,
+    security: Arc<SecurityPolicy>,
+    serve: ServeOptions,
+}
 
-            if path.ends_with('/') {
-                path += "index.html";
-            }
+/// Body and metadata of a successfully resolved request.
+struct Served {
+    content: Vec<u8>,
+    total: u64,
+    range: RangeRequest,
+    /// Path after the SPA fallback, decides the MIME type.
+    path: String,
+    /// `br`/`gzip` when a precompressed variant from the bundle is served.
+    encoding: Option<&'static str>,
+    etag: Option<String>,
+    not_modified: bool,
+}
+
+/// Answers a `pyframe://` request from the resource bundle (`<id_name>` host)
+/// or the local file system (`filesystem` host), honoring `Range` headers.
+fn protocol_response(sources: &ProtocolSources, request: &Request<Vec<u8>>) -> Response<Cow<'static, [u8]>> {
+    let ProtocolSources {
+        resource,
+        fs_scope,
+        id_name,
+        page_origin,
+        security,
+        serve,
+    } = sources;
+    let id_name = id_name.as_str();
+    let hostname = request.uri().host().unwrap_or(id_name);
 
-            let result = (|| -> anyhow::Result<Vec<u8>> {
-                if hostname == &id_name {
-                    let path = path.strip_prefix('/').unwrap_or("index.html");
-                    custom_protocol_app.resource().load(path)
-                } else if hostname == "filesystem" {
-                    let path = path.strip_prefix('/').unwrap_or("index.html");
-                    let path = std::path::Path::new(path); // für alle Plattformen!
-                    Ok(std::fs::read(path)?)
-                } else {
-                    Err(anyhow::anyhow!("Invalid hostname: {}", hostname))
+    let mut path = request.uri().path().to_string();
+
+    if path.ends_with('/') {
+        path += "index.html";
+    }
+    let request_header = |name: header::HeaderName| request.headers().get(name).and_then(|value| value.to_str().ok());
+    let range_header = request_header(header::RANGE);
+
+    let result = (|| -> anyhow::Result<Served> {
+        let path = path.strip_prefix('/').unwrap_or("index.html");
+        if hostname == id_name {
+            serve_bundle(
+                resource,
+                serve,
+                security,
+                path,
+                range_header,
+                request_header(header::ACCEPT_ENCODING),
+                request_header(header::IF_NONE_MATCH),
+            )
+        } else if hostname == "filesystem" {
+            // file:// übernimmt Prozent-Dekodierung und Laufwerksbuchstaben, für alle Plattformen!
+            let file_path = url::Url::parse(&format!("file:///{path}"))?
+                .to_file_path()
+                .map_err(|_| anyhow::anyhow!("Invalid file path: {}", path))?;
+            let file_path = lock!(fs_scope)?.resolve(&file_path)?;
+            let mut file = std::fs::File::open(file_path)?;
+            let total = file.metadata()?.len();
+            // 206 nur auf ein echtes Range, ohne bekommt jeder Client die ganze Datei mit 200
+            let range = match RangeRequest::parse(range_header, total) {
+                RangeRequest::Partial(r) => RangeRequest::Partial(r.limit(MAX_RANGE_CHUNK)),
+                range => range,
+            };
+            // Nur der angefragte Ausschnitt wird gelesen
+            let content = match range {
+                RangeRequest::Full => {
+                    // wry braucht den ganzen Body; blockweise lesen statt vorab `total` Bytes zu reservieren,
+                    // und nicht über die gemeldete Größe hinaus, falls die Datei wächst
+                    let mut content = Vec::with_capacity(total.min(MAX_RANGE_CHUNK) as usize);
+                    file.take(total).read_to_end(&mut content)?;
+                    content
                 }
-            })();
-
-            let origin = get_host_from_url(&request.uri().to_string()).unwrap_or("*".to_string());
-
-            match result {
-                Err(err) => wry::http::Response::builder()
-                    .status(404)
-                    .header(wry::http::header::CONTENT_TYPE, "text/plain; charset=utf-8")
-                    .body(std::borrow::Cow::Owned(err.to_string().into_bytes()))
-                    .unwrap(),
-
-                Ok(content) => {
-                    let mime_type = mime_guess::from_path(path)
-                        .first()
-                        .unwrap_or(mime_guess::mime::TEXT_PLAIN)
-                        .to_string();
-
-                    wry::http::Response::builder()
-                        .status(200)
-                        .header(wry::http::header::CONTENT_TYPE, mime_type)
-                        .header("Access-Control-Allow-Origin", origin)
-                        .body(std::borrow::Cow::Owned(content))
-                        .unwrap()
+                RangeRequest::Partial(r) => {
+                    file.seek(SeekFrom::Start(r.start))?;
+                    let mut content = vec![0; r.count() as usize];
+                    file.read_exact(&mut content)?;
+                    content
                 }
+                RangeRequest::Unsatisfiable => Vec::new(),
+            };
+            Ok(Served {
+                content,
+                total,
+                range,
+                path: path.to_string(),
+                encoding: None,
+                etag: None,
+                not_modified: false,
+            })
+        } else {
+            Err(anyhow::anyhow!("Invalid hostname: {}", hostname))
+        }
+    })();
+
+    let origin = match hostname {
+        "filesystem" => page_origin.clone(),
+        _ => get_host_from_url(&request.uri().to_string()).unwrap_or("*".to_string()),
+    };
+
+    match result {
+        Err(err) if err.is::<ScopeDenied>() => Response::builder()
+            .status(403)
+            .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+            .body(Cow::Owned(err.to_string().into_bytes()))
+            .unwrap(),
+        Err(err) => Response::builder()
+            .status(404)
+            .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+            .body(Cow::Owned(err.to_string().into_bytes()))
+            .unwrap(),
+
+        Ok(served) => {
+            let mime_type = mime_for(&served.path);
+            let is_html = mime_type == mime_guess::mime::TEXT_HTML;
+            let total = served.total;
+
+            let mut builder = Response::builder()
+                .header(header::CONTENT_TYPE, mime_type.to_string())
+                .header(header::ACCEPT_RANGES, "bytes")
+                .header("Access-Control-Allow-Origin", origin);
+            let security_headers = security.headers_for(&served.path, is_html);
+            if hostname == id_name
+                && !security_headers
+                    .iter()
+                    .any(|(key, _)| key.eq_ignore_ascii_case("cache-control"))
+            {
+                builder = builder.header(header::CACHE_CONTROL, serve.cache_control(is_html));
+            }
+            for (key, value) in security_headers {
+                builder = builder.header(key, value);
+            }
+            if let Some(etag) = &served.etag {
+                builder = builder.header(header::ETAG, etag);
+            }
+            if let Some(encoding) = served.encoding {
+                builder = builder
+                    .header(header::CONTENT_ENCODING, encoding)
+                    .header(header::VARY, "Accept-Encoding");
+            }
+            let builder = match served.range {
+                _ if served.not_modified => builder.status(304),
+                RangeRequest::Full => builder.status(200),
+                RangeRequest::Partial(r) => builder
+                    .status(206)
+                    .header(header::CONTENT_RANGE, r.content_range(total)),
+                RangeRequest::Unsatisfiable => builder
+                    .status(416)
+                    .header(header::CONTENT_RANGE, format!("bytes */{total}")),
+            };
+            builder
+                .header(header::CONTENT_LENGTH, served.content.len())
+                .body(Cow::Owned(served.content))
+                .unwrap()
+        }
+    }
+}
+
+/// Loads `path` from the resource bundle with SPA fallback, precompressed variants and `ETag`.
+fn serve_bundle(
+    resource: &Arc<dyn ResourceManager>,
+    serve: &ServeOptions,
+    security: &SecurityPolicy,
+    path: &str,
+    range_header: Option<&str>,
+    accept_encoding: Option<&str>,
+    if_none_match: Option<&str>,
+) -> anyhow::Result<Served> {
+    let path = match serve.spa_fallback_for(path) {
+        Some(fallback) if !resource.exists(path) => fallback.to_string(),
+        _ => path.to_string(),
+    };
+    let is_html = mime_for(&path) == mime_guess::mime::TEXT_HTML;
+
+    // Vorkomprimierte Varianten nur für ganze Antworten, HTML braucht ohnehin die Nonce
+    let mut variant = (path.clone(), None);
+    if serve.precompressed.unwrap_or(true) && range_header.is_none() && !is_html {
+        for (encoding, extension) in [("br", "br"), ("gzip", "gz")] {
+            let compressed = format!("{path}.{extension}");
+            if accepts_encoding(accept_encoding, encoding) && resource.exists(&compressed) {
+                variant = (compressed, Some(encoding));
+                break;
             }
+        }
+    }
+    let (variant_path, encoding) = variant;
+
+    // Die Nonce gehört zum Dokument, sonst liefert der Cache die eines anderen Fensters
+    let etag = match is_html {
+        true => format!("\"{}-{}\"", resource.hash(&variant_path)?, security.nonce_tag()),
+        false => format!("\"{}\"", resource.hash(&variant_path)?),
+    };
+    if if_none_match.is_some_and(|tags| tags.split(',').any(|tag| tag.trim() == etag || tag.trim() == "*")) {
+        return Ok(Served {
+            content: Vec::new(),
+            total: 0,
+            range: RangeRequest::Full,
+            path,
+            encoding,
+            etag: Some(etag),
+            not_modified: true,
+        });
+    }
+
+    let mut data = resource.load(&variant_path)?;
+    if is_html {
+        data = security.inject_nonce(&data).into_owned();
+    }
+    let total = data.len() as u64;
+    let range = RangeRequest::parse(range_header, total);
+    let content = match range {
+        RangeRequest::Full => data,
+        RangeRequest::Partial(r) => data[r.start as usize..=r.end as usize].to_vec(),
+        RangeRequest::Unsatisfiable => Vec::new(),
+    };
+    Ok(Served {
+        content,
+        total,
+        range,
+        path,
+        encoding,
+        etag: Some(etag),
+        not_modified: false,
+    })
+}
+
+fn mime_for(path: &str) -> mime_guess::Mime {
+    mime_guess::from_path(path)
+        .first()
+        .unwrap_or(mime_guess::mime::TEXT_PLAIN)
+}
+
+/// `Accept-Encoding` enthält `encoding` mit einer Qualität über 0.
+fn accepts_encoding(header: Option<&str>, encoding: &str) -> bool {
+    header.is_some_and(|header| {
+        header.split(',').any(|entry| {
+            let mut parts = entry.split(';').map(str::trim);
+            let name_matches = parts.next().is_some_and(|name| name.eq_ignore_ascii_case(encoding));
+            let quality = parts
+                .find_map(|param| param.strip_prefix("q=")?.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            name_matches && quality > 0.0
         })
-        .with_url(&entry_url);
-    Ok(builder)
+    })
 }
 
 #[allow(clippy::op_ref)]
@@ -130,16 +425,21 @@ pub fn render_web_protocol(
 pub fn build_full_url(
     builder: wry::WebViewBuilder,
     app: std::sync::Arc<crate::CoreApplication>,
+    options: &WindowConfig,
+    new_window: Arc<NewWindowHandler>,
 ) -> anyhow::Result<wry::WebViewBuilder> {
     // Hole Basis-URL (debug_entry) oder verwende den Standard
     let debug_entry = app.launch_info.options.debug_entry.clone();
-    let default_url = "http://localhost:8080";
-    let mut url = url::Url::parse(&debug_entry.unwrap_or_else(|| default_url.to_owned()))?;
+    let mut url = url::Url::parse(&debug_entry.unwrap_or_else(|| DEFAULT_SERVER_URL.to_owned()))?;
 
     // Hole optionalen Pfad (entry) und setze ihn, falls vorhanden
-    if let Some(p) = app.launch_info.options.window.entry.clone() {
-        let clean_path = p.trim_start_matches('/'); // Entfernt führende Slashes
-        url.set_path(clean_path);
+    match window_entry(&app, options) {
+        Some(p) if is_absolute_url(&p) => url = url::Url::parse(&p)?,
+        Some(p) => {
+            let clean_path = p.trim_start_matches('/'); // Entfernt führende Slashes
+            url.set_path(clean_path);
+        }
+        None => {}
     }
 
     // Finale URL als String
@@ -152,7 +452,11 @@ pub fn build_full_url(
         // Parse die URI der Navigationsanfrage
         if let Ok(nav_url) = url::Url::parse(&uri) {
             // Erlaube nur Navigieren zu derselben Host/Port-Kombination
-            nav_url.origin() == allow_url.origin()
+            if nav_url.origin() == allow_url.origin() {
+                return true;
+            }
+            new_window.handle(uri, "navigation");
+            false
         } else {
             false // Ungültige URL -> blocken
         }
@@ -0,0 +1,157 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/// Inclusive byte range of a `Range: bytes=...` request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+    /// `bytes=500-` without an end, the server may answer with less.
+    pub open_ended: bool,
+}
+
+impl ByteRange {
+    pub fn count(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Kürzt offene Bereiche auf höchstens `max` Bytes.
+    pub fn limit(mut self, max: u64) -> Self {
+        if self.open_ended && self.count() > max {
+            self.end = self.start + max - 1;
+        }
+        self
+    }
+
+    pub fn content_range(&self, total: u64) -> String {
+        format!("bytes {}-{}/{}", self.start, self.end, total)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeRequest {
+    /// No or unsupported `Range` header, answer `200` with the whole body.
+    Full,
+    Partial(ByteRange),
+    /// Answer `416` with `Content-Range: bytes */<total>`.
+    Unsatisfiable,
+}
+
+impl RangeRequest {
+    /// Parses a `Range` header for a body of `total` bytes.
+    /// Only the first range of a multi-range request is answered.
+    pub fn parse(header: Option<&str>, total: u64) -> Self {
+        let Some(spec) = header.and_then(|header| header.trim().strip_prefix("bytes=")) else {
+            return Self::Full;
+        };
+        let first = spec.split(',').next().unwrap_or_default().trim();
+        let Some((start, end)) = first.split_once('-') else {
+            return Self::Full;
+        };
+
+        let range = match (start.trim(), end.trim()) {
+            // bytes=-500: die letzten 500 Bytes
+            ("", suffix) => {
+                let Ok(suffix) = suffix.parse::<u64>() else {
+                    return Self::Full;
+                };
+                if suffix == 0 || total == 0 {
+                    return Self::Unsatisfiable;
+                }
+                ByteRange {
+                    start: total.saturating_sub(suffix),
+                    end: total - 1,
+                    open_ended: false,
+                }
+            }
+            (start, end) => {
+                let Ok(start) = start.parse::<u64>() else {
+                    return Self::Full;
+                };
+                let end = match end {
+                    "" => None,
+                    end => match end.parse::<u64>() {
+                        Ok(end) if end >= start => Some(end),
+                        _ => return Self::Full,
+                    },
+                };
+                if start >= total {
+                    return Self::Unsatisfiable;
+                }
+                ByteRange {
+                    start,
+                    end: end.map_or(total - 1, |end| end.min(total - 1)),
+                    open_ended: end.is_none(),
+                }
+            }
+        };
+        Self::Partial(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partial(start: u64, end: u64, open_ended: bool) -> RangeRequest {
+        RangeRequest::Partial(ByteRange { start, end, open_ended })
+    }
+
+    #[test]
+    fn without_range_header_answers_full() {
+        assert_eq!(RangeRequest::parse(None, 100), RangeRequest::Full);
+        assert_eq!(RangeRequest::parse(Some("items=0-1"), 100), RangeRequest::Full);
+    }
+
+    #[test]
+    fn suffix_range_takes_the_last_bytes() {
+        assert_eq!(RangeRequest::parse(Some("bytes=-10"), 100), partial(90, 99, false));
+        // Längere Suffixe liefern die ganze Datei
+        assert_eq!(RangeRequest::parse(Some("bytes=-500"), 100), partial(0, 99, false));
+        assert_eq!(RangeRequest::parse(Some("bytes=-0"), 100), RangeRequest::Unsatisfiable);
+    }
+
+    #[test]
+    fn open_ended_range_runs_to_the_end() {
+        let range = RangeRequest::parse(Some("bytes=40-"), 100);
+        assert_eq!(range, partial(40, 99, true));
+        let RangeRequest::Partial(range) = range else {
+            unreachable!()
+        };
+        assert_eq!(
+            range.limit(10),
+            ByteRange {
+                start: 40,
+                end: 49,
+                open_ended: true
+            }
+        );
+        assert_eq!(range.content_range(100), "bytes 40-99/100");
+    }
+
+    #[test]
+    fn closed_range_is_clamped_to_the_body() {
+        assert_eq!(RangeRequest::parse(Some("bytes=0-9"), 100), partial(0, 9, false));
+        assert_eq!(RangeRequest::parse(Some("bytes=90-200"), 100), partial(90, 99, false));
+        assert_eq!(RangeRequest::parse(Some("bytes=20-10"), 100), RangeRequest::Full);
+    }
+
+    #[test]
+    fn multi_range_answers_the_first_range() {
+        assert_eq!(RangeRequest::parse(Some("bytes=0-9, 50-59"), 100), partial(0, 9, false));
+    }
+
+    #[test]
+    fn out_of_bounds_is_unsatisfiable() {
+        assert_eq!(
+            RangeRequest::parse(Some("bytes=100-"), 100),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(
+            RangeRequest::parse(Some("bytes=150-160"), 100),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(RangeRequest::parse(Some("bytes=-10"), 0), RangeRequest::Unsatisfiable);
+    }
+}
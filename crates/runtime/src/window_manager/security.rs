@@ -0,0 +1,168 @@
+// Copyright 2025-2030 PyFrame Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    log_err,
+    options::{AppOptions, SecurityOptions},
+    utils::make_base_url,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use regex::Regex;
+use std::{borrow::Cow, collections::HashMap, sync::LazyLock};
+use wry::http::{HeaderName, HeaderValue};
+
+/// Headers every response gets unless a rule overrides them.
+const DEFAULT_HEADERS: &[(&str, &str)] = &[
+    ("X-Content-Type-Options", "nosniff"),
+    ("Referrer-Policy", "no-referrer"),
+];
+
+/// Öffnende `<script>`- und `<style>`-Tags für [`SecurityPolicy::inject_nonce`].
+static NONCE_TAGS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)<(script|style)(\s[^>]*)?>").expect("valid regex"));
+
+/// Compiled form of [`SecurityOptions`] for one window.
+pub struct SecurityPolicy {
+    csp: Option<String>,
+    rules: Vec<(glob::Pattern, Vec<(String, String)>)>,
+    inject_nonce: bool,
+    nonce: String,
+}
+
+impl SecurityPolicy {
+    pub fn new(app_options: &AppOptions) -> Self {
+        let options = app_options.security.clone().unwrap_or_default();
+        let nonce = generate_nonce();
+        let csp = match options.csp {
+            Some(csp) if csp.trim().is_empty() => None,
+            Some(csp) => Some(csp),
+            None => Some(default_csp(app_options)),
+        }
+        // Mehrzeilige Policies aus der Konfiguration sind als Header-Wert ungültig
+        .map(|csp| {
+            csp.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .replace("{nonce}", &nonce)
+        });
+
+        let rules = options
+            .headers
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|rule| match glob::Pattern::new(&rule.pattern) {
+                Ok(pattern) => Some((pattern, valid_headers(rule.headers))),
+                Err(err) => {
+                    log_err!(format!("Invalid header pattern '{}': {}", rule.pattern, err));
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            csp,
+            rules,
+            inject_nonce: options.inject_nonce.unwrap_or(true),
+            nonce,
+        }
+    }
+
+    /// Short, ETag-safe fingerprint of the nonce.
+    pub fn nonce_tag(&self) -> String {
+        self.nonce.chars().filter(char::is_ascii_alphanumeric).take(8).collect()
+    }
+
+    /// Init script that exposes the nonce as `PyFrame.nonce`.
+    pub fn nonce_script(&self) -> String {
+        format!("window.__PYFRAME_NONCE__ = \"{}\";", self.nonce)
+    }
+
+    /// Response headers for `path`; the CSP is only sent with HTML documents.
+    /// Rules are applied in order, later matches override earlier ones.
+    pub fn headers_for(&self, path: &str, is_html: bool) -> Vec<(String, String)> {
+        let mut headers: Vec<(String, String)> = DEFAULT_HEADERS
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        if let (Some(csp), true) = (&self.csp, is_html) {
+            headers.push(("Content-Security-Policy".to_string(), csp.clone()));
+        }
+
+        let path = format!("/{}", path.trim_start_matches('/'));
+        for (pattern, rule_headers) in &self.rules {
+            if !pattern.matches(&path) {
+                continue;
+            }
+            for (key, value) in rule_headers {
+                headers.retain(|(existing, _)| !existing.eq_ignore_ascii_case(key));
+                // Leerer Wert entfernt einen Standard-Header
+                if !value.is_empty() {
+                    headers.push((key.clone(), value.replace("{nonce}", &self.nonce)));
+                }
+            }
+        }
+        headers
+    }
+
+    /// Adds the nonce to `<script>` and `<style>` tags of a bundled HTML document.
+    pub fn inject_nonce<'a>(&self, html: &'a [u8]) -> Cow<'a, [u8]> {
+        if !self.inject_nonce {
+            return Cow::Borrowed(html);
+        }
+        let Ok(text) = std::str::from_utf8(html) else {
+            return Cow::Borrowed(html);
+        };
+        match NONCE_TAGS.replace_all(text, |caps: &regex::Captures| {
+            let attributes = caps.get(2).map_or("", |m| m.as_str());
+            if attributes.to_ascii_lowercase().contains("nonce=") {
+                caps[0].to_string()
+            } else {
+                format!("<{} nonce=\"{}\"{}>", &caps[1], self.nonce, attributes)
+            }
+        }) {
+            Cow::Borrowed(_) => Cow::Borrowed(html),
+            Cow::Owned(text) => Cow::Owned(text.into_bytes()),
+        }
+    }
+}
+
+fn valid_headers(headers: HashMap<String, String>) -> Vec<(String, String)> {
+    headers
+        .into_iter()
+        .filter(|(key, value)| {
+            let valid = HeaderName::from_bytes(key.as_bytes()).is_ok() && HeaderValue::from_str(value).is_ok();
+            if !valid {
+                log_err!(format!("Invalid response header '{}: {}'", key, value));
+            }
+            valid
+        })
+        .collect()
+}
+
+/// Strikter Standard: nur eigene Inhalte, Skripte nur mit Nonce, Verbindungen nur zum Backend.
+fn default_csp(options: &AppOptions) -> String {
+    let backend = format!("http://{0}:{1} ws://{0}:{1}", options.host, options.port);
+    let filesystem = make_base_url("pyframe", "filesystem");
+    [
+        "default-src 'self'".to_string(),
+        "script-src 'self' 'nonce-{nonce}'".to_string(),
+        "style-src 'self' 'unsafe-inline'".to_string(),
+        format!("img-src 'self' data: blob: {filesystem}"),
+        format!("media-src 'self' blob: {filesystem}"),
+        "font-src 'self' data:".to_string(),
+        format!("connect-src 'self' ipc: http://ipc.localhost {backend}"),
+        "object-src 'none'".to_string(),
+        "base-uri 'self'".to_string(),
+        "form-action 'self'".to_string(),
+        "frame-ancestors 'none'".to_string(),
+    ]
+    .join("; ")
+}
+
+/// 128 Bit aus dem Zufallsgenerator des Betriebssystems.
+fn generate_nonce() -> String {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).expect("OS random number generator unavailable");
+    STANDARD.encode(bytes)
+}
@@ -4,15 +4,17 @@
 
 use super::{builder::FrameBuilder, WindowManager};
 use crate::{
-    options::window::WindowConfig,
+    lock, log_if_err,
+    options::window::{WindowConfig, WindowShowWhen},
     unsafe_impl_sync_send,
-    utils::{self, FrameEvent, FrameEventLoopProxy, FrameWindowTarget},
+    utils::{self, FrameEvent, FrameEventLoopProxy, FrameWindowTarget, UserEvent},
     CoreApplication,
 };
 use anyhow::Result;
 use std::{
     ops::Deref,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 use tao::window::{Window, WindowId};
 use wry::WebView;
@@ -20,6 +22,12 @@ use wry::WebView;
 #[allow(dead_code)]
 pub struct FrameWindowState {
     pub is_block_closed_requested: bool,
+    /// Set while the window is hidden and waits for a ready signal.
+    pub pending_show: Option<WindowShowWhen>,
+    /// Event patterns (`window.moved`, `window.*`, `*`) for high-frequency events.
+    pub subscriptions: Vec<String>,
+    pub minimized: bool,
+    pub maximized: bool,
 }
 
 unsafe_impl_sync_send!(FrameWindow);
@@ -36,6 +44,8 @@ pub struct FrameWindow {
     pub window: Window,
     pub window_id: WindowId,
     pub webview: WebView,
+    /// Resize border for undecorated windows, see [`crate::hylper::hit_test`].
+    pub resize_inset: f64,
     app: Arc<CoreApplication>,
     event_loop_proxy: FrameEventLoopProxy,
     pub state: Mutex<FrameWindowState>,
@@ -55,19 +65,54 @@ impl FrameWindow {
         let window = utils::menu_provider(&app, window)?;
         let window_id = window.id();
 
+        let pending_show = options.pending_show();
+        if pending_show.is_some() {
+            let proxy = app.proxy.clone();
+            let timeout = Duration::from_millis(options.window_show_timeout_ms.unwrap_or(5_000));
+            std::thread::spawn(move || {
+                std::thread::sleep(timeout);
+                log_if_err!(proxy
+                    .send_event(UserEvent::WindowReady(window_id))
+                    .map_err(|_| anyhow::anyhow!("Failed to send event")));
+            });
+        }
+
         Ok(crate::utils::arc(Self {
             app: app.clone(),
             id,
             window,
             window_id,
             webview,
+            resize_inset: options.resize_inset(),
             //menu: init_menu_bar,
             event_loop_proxy: app.proxy.clone(),
             state: Mutex::new(FrameWindowState {
                 is_block_closed_requested: false,
+                pending_show,
+                subscriptions: Vec::new(),
+                minimized: false,
+                maximized: options.window_maximized.unwrap_or(false),
             }),
         }))
     }
+
+    /// Shows a window created with `window_show_when` once `signal` satisfies its mode.
+    /// Returns `true` if the window became visible.
+    pub fn mark_ready(self: &Arc<Self>, signal: WindowShowWhen) -> Result<bool> {
+        let mut state = lock!(self.state)?;
+        let show = match (state.pending_show, signal) {
+            (None, _) => false,
+            (Some(WindowShowWhen::Load), _) => true,
+            (Some(pending), signal) => pending == signal,
+        };
+        if show {
+            state.pending_show = None;
+            self.window.set_visible(true);
+            self.emit_event("window.shown", serde_json::json!(null))?;
+        }
+        Ok(show)
+    }
+
     pub fn post_message<P: serde::Serialize>(self: &Arc<Self>, payload: P) -> anyhow::Result<()> {
         // JSON-String des Payloads
         let payload = serde_json::to_string(&payload)?;
@@ -108,6 +153,59 @@ impl FrameWindow {
         })
     }
 
+    /// Emits a `PyFrame` event in this window and forwards it to Python.
+    pub fn emit_event<P: serde::Serialize>(self: &Arc<Self>, event: &str, payload: P) -> anyhow::Result<()> {
+        let payload = serde_json::to_value(payload)?;
+        self.send_ipc_event(event, payload.clone())?;
+        self.post_message(Self::python_event(self.id, event, payload))
+    }
+
+    /// Like [`FrameWindow::emit_event`], but only if the window subscribed to `event`.
+    pub fn emit_subscribed_event<P: serde::Serialize>(self: &Arc<Self>, event: &str, payload: P) -> anyhow::Result<()> {
+        if self.is_subscribed(event) {
+            self.emit_event(event, payload)?;
+        }
+        Ok(())
+    }
+
+    pub fn is_subscribed(&self, event: &str) -> bool {
+        lock!(self.state)
+            .map(|state| state.subscriptions.iter().any(|pattern| event_matches(pattern, event)))
+            .unwrap_or(false)
+    }
+
+    /// Message for the `window_event` protocol on the Python side.
+    pub fn python_event(id: u8, event: &str, data: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "protocol": "window_event",
+            "payload": {
+                "windowId": id,
+                "event": event,
+                "data": data,
+            }
+        })
+    }
+
+    /// Emits `window.minimized` / `window.maximized` when the state changed since the last call.
+    pub fn sync_window_state(self: &Arc<Self>) -> anyhow::Result<()> {
+        let (minimized, maximized) = (self.window.is_minimized(), self.window.is_maximized());
+        let (minimized_changed, maximized_changed) = {
+            let mut state = lock!(self.state)?;
+            let changed = (state.minimized != minimized, state.maximized != maximized);
+            state.minimized = minimized;
+            state.maximized = maximized;
+            changed
+        };
+
+        if minimized_changed {
+            self.emit_event("window.minimized", minimized)?;
+        }
+        if maximized_changed {
+            self.emit_event("window.maximized", maximized)?;
+        }
+        Ok(())
+    }
+
     pub fn send_ipc_callback<D: serde::Serialize + std::fmt::Debug>(self: &Arc<Self>, data: D) -> anyhow::Result<()> {
         self.send_ipc_event("ipc.callback", serde_json::json!(data))?;
         Ok(())
@@ -115,3 +213,12 @@ impl FrameWindow {
     #[cfg(target_os = "macos")]
     pub fn switch_menu(self: &Arc<Self>) {}
 }
+
+/// Same matching rules as `PyFrame.__emit__`: exact name, `<prefix>.*` or `*`.
+fn event_matches(pattern: &str, event: &str) -> bool {
+    match pattern.strip_suffix(".*") {
+        _ if pattern == "*" || pattern == event => true,
+        Some(prefix) => event.split('.').next() == Some(prefix),
+        None => false,
+    }
+}
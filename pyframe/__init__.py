@@ -1,26 +1,55 @@
-from _pyframe import __version__, create_ico, create_webview
+from _pyframe import (
+    __version__,
+    bundle_public_key,
+    create_ico,
+    create_webview,
+    pack_resources,
+)
 
 from .app import PyFrame
 from .executers.pyinvoker import command
 from .frame import Frame, Menu, SubMenu, create_about_metadata
-from .handler import dialog, extra, notify, resource, webview, window, window_extra
+from .handler import (
+    dialog,
+    extra,
+    fs,
+    i18n,
+    lifecycle,
+    notify,
+    resource,
+    webview,
+    window,
+    window_extra,
+)
 from .model.models import (
     AboutMetadata,
     AcceleratorCode,
     AcceleratorModifier,
     ActivationPolicy,
     AppOptions,
+    BundleOptions,
     CheckMenuItem,
+    FileOperationOptions,
     FrameBackgroundThrottlingPolicy,
     FrameShortcutOption,
     HeaderData,
+    HeaderRule,
+    HotReloadOptions,
+    I18nOptions,
     IconMenuItem,
     LinuxWindowConfig,
     MacOSWindowConfig,
     MenuFrame,
     MenuItem,
+    NewWindowAction,
+    NewWindowOptions,
+    NewWindowRule,
     PredefinedMenuItem,
+    SecurityOptions,
+    ServeOptions,
+    ShutdownOptions,
     SocketSettings,
+    SplashOptions,
     Submenu,
     SystemTray,
     WindowConfig,
@@ -32,10 +61,14 @@ __all__ = [
     "create_about_metadata",
     "resource",
     "extra",
+    "fs",
+    "i18n",
     "window_extra",
     "notify",
     "create_webview",
     "create_ico",
+    "pack_resources",
+    "bundle_public_key",
     "PyFrame",
     "ActivationPolicy",
     "FrameBackgroundThrottlingPolicy",
@@ -46,6 +79,7 @@ __all__ = [
     "Menu",
     "SubMenu",
     "window",
+    "lifecycle",
     "dialog",
     "webview",
     "command",
@@ -61,7 +95,19 @@ __all__ = [
     "AcceleratorCode",
     "AcceleratorModifier",
     "AppOptions",
+    "BundleOptions",
+    "FileOperationOptions",
+    "HeaderRule",
+    "HotReloadOptions",
+    "I18nOptions",
+    "SecurityOptions",
+    "ServeOptions",
+    "ShutdownOptions",
     "SocketSettings",
+    "SplashOptions",
     "FrameShortcutOption",
     "HeaderData",
+    "NewWindowAction",
+    "NewWindowOptions",
+    "NewWindowRule",
 ]
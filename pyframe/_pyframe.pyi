@@ -1,4 +1,17 @@
+from typing import Literal
+
 __version__: str
 
-def create_webview(config_json: str): ...
+def bundle_public_key(signing_key: str) -> str: ...
+def create_webview(config_json: str) -> int: ...
 def create_ico(source_path: str, target_path: str): ...
+def pack_resources(
+    source_dir: str,
+    target_dir: str,
+    include: list[str] | None = None,
+    exclude: list[str] | None = None,
+    compression: Literal["store", "deflate", "zstd"] | None = None,
+    signing_key: str | None = None,
+    encryption_key: str | None = None,
+    verify: bool = False,
+) -> dict[str, int]: ...
@@ -6,6 +6,7 @@ from fastapi.middleware.cors import CORSMiddleware
 from fastapi.responses import JSONResponse
 
 from . import background_tasks, core, outbox
+from .executers.window_event_executer import window_events
 from .model.models import AppOptions, SocketSettings
 from .runtime import endless_state_loop
 
@@ -16,6 +17,8 @@ class FrameRESTAPI:
     ):
         self.config = config
         self.shutdown_event: Optional[asyncio.Event] = None
+        self.splash_message: Optional[str] = None
+        self.exit_code: Optional[int] = None
         self.app = FastAPI(**(fastapi_config or {}))
         self._configure_cors()
         self._create_routes()
@@ -44,12 +47,23 @@ class FrameRESTAPI:
             settings_dict["socketHost"] = self.config.host
             return JSONResponse(content=settings_dict)
 
+        @self.app.get("/pyframe_splash_progress")
+        async def get_splash_progress():
+            return JSONResponse({"message": self.splash_message})
+
         @self.app.get("/server_shutdown")
-        async def shutdown():
+        async def shutdown(exit_code: int = 0):
+            self.exit_code = exit_code
             if self.shutdown_event:
                 self.shutdown_event.set()
             return JSONResponse({"status": 200})
 
+        @self.app.post("/pyframe_app_event")
+        async def app_event(request: Request):
+            # Fallback of the runtime when no window is left to forward events
+            await window_events.trigger(await request.json())
+            return JSONResponse({"status": 200})
+
     async def _on_startup(self) -> None:
         core.state = core.State.STARTING
         core.loop = asyncio.get_running_loop()
@@ -10,30 +10,40 @@ import multiprocessing
 import signal
 import sys
 import threading
-import time
-from typing import Any, Dict, List, Literal, Optional
+from typing import Any, Awaitable, Callable, Dict, List, Literal, Optional, Union
 
 import uvicorn
 
 from ..api import FrameRESTAPI
 from ..connection import Connection
+from ..executers.window_event_executer import window_events
 from ..frame.menu import Menu
 from ..frame.window import Frame
 from ..model.models import (
     AppOptions,
+    BundleOptions,
     CheckMenuItem,
+    FileOperationOptions,
+    HeaderRule,
+    HotReloadOptions,
+    I18nOptions,
     IconMenuItem,
     LinuxWindowConfig,
     MacOSWindowConfig,
     MenuFrame,
     MenuItem,
+    NewWindowOptions,
     PredefinedMenuItem,
+    SecurityOptions,
+    ServeOptions,
+    ShutdownOptions,
     SocketSettings,
+    SplashOptions,
     Submenu,
     SystemTray,
     WindowsWindowConfig,
 )
-from ..runtime import run_webview
+from ..runtime import request, run_webview
 from ..utils import suppress_stderr
 
 
@@ -52,6 +62,7 @@ class PyFrame:
         debug_devtools: Optional[bool] = None,
         debug_resource: Optional[str] = None,
         debug_entry: Optional[str] = None,
+        resource_archive: Optional[str] = None,
         fastapi_config: Optional[Dict[str, Any]] = None,
         enable_py_api: Optional[bool] = True,
         web_proto: Optional[str] = None,
@@ -68,6 +79,8 @@ class PyFrame:
             debug_devtools: Whether to enable DevTools in debug mode.
             debug_resource: Optional debug resource path.
             debug_entry: Optional debug entry file.
+            resource_archive: Optional .zip of web assets served instead of the
+                bundle; debug_resource takes precedence.
             fastapi_config: Additional FastAPI configuration.
         """
         self.host = host
@@ -82,6 +95,7 @@ class PyFrame:
             debug_devtools=debug_devtools,
             debug_resource=debug_resource,
             debug_entry=debug_entry,
+            resource_archive=resource_archive,
             menu_mode=menu_mode,
         )
 
@@ -102,6 +116,7 @@ class PyFrame:
 
         self.api_thread: Optional[threading.Thread] = None
         self.webview_process: Optional[multiprocessing.Process] = None
+        self._shutdown_callbacks: List[Callable[[int], Union[None, Awaitable[None]]]] = []
 
     def configure_socketio_on_window(self, **kwargs: Any) -> None:
         """
@@ -167,6 +182,279 @@ class PyFrame:
         """
         self.config.tray = tray
 
+    def new_window_policy(self, policy: NewWindowOptions) -> None:
+        """
+        Set how window.open, target=_blank and external navigation are handled.
+
+        Args:
+            policy: Default action and URL rules (window, browser or deny).
+        """
+        self.config.new_window = policy
+
+    def splash_screen(self, options: SplashOptions) -> None:
+        """
+        Show a splash window until the main window has finished loading.
+
+        Args:
+            options: Image or HTML, size and timing of the splash.
+        """
+        self.config.splash = options
+
+    def splash_progress(self, message: str) -> None:
+        """
+        Update the progress text shown on the splash window.
+
+        Args:
+            message: Text passed to PyFrameSplash.setProgress.
+        """
+        self.fastapi_app.splash_message = message
+
+    def shutdown_options(
+        self,
+        hook: Optional[Literal["http", "python", "none"]] = None,
+        timeout_ms: Optional[int] = None,
+    ) -> None:
+        """
+        Configure what happens with the backend when the app quits.
+
+        Args:
+            hook: "http" (default) calls /server_shutdown, "python" runs the
+                on_shutdown callbacks, "none" exits right away.
+            timeout_ms: Hard limit after which the app exits in any case.
+        """
+        self.config.shutdown = ShutdownOptions(hook=hook, timeout_ms=timeout_ms)
+
+    def close_behavior(
+        self,
+        behavior: Literal["quit", "hide", "keepRunning"],
+        reopen_shortcut: Optional[str] = None,
+    ) -> None:
+        """
+        Configure what closing the main window does. Tray-only apps
+        (menu_mode="tray") hide the window by default, all others quit.
+
+        Args:
+            behavior: "quit" exits the app, "hide" only hides the main window,
+                "keepRunning" closes it and keeps the app alive until app.quit.
+            reopen_shortcut: Global accelerator, e.g. "CmdOrCtrl+Shift+Space",
+                that shows the main window again. A click on the tray icon
+                does the same.
+        """
+        self.config.close_behavior = behavior
+        if reopen_shortcut is not None:
+            self.config.reopen_shortcut = reopen_shortcut
+
+    def filesystem_scope(self, *directories: str) -> None:
+        """
+        Allow pages to read these directories through pyframe://filesystem/
+        and the fs.* API. Everything else is answered with 403 or a scope
+        error; fs.* may also read the file_operations() write scope.
+        Directories picked through dialog.pickDir are added at runtime.
+
+        Args:
+            directories: Absolute paths or paths starting with one of the
+                placeholders $DATA, $CACHE, $TEMP, $HOME, $DOCUMENTS or $DOWNLOADS,
+                e.g. "$DATA/media".
+        """
+        scope = self.config.filesystem_scope or []
+        scope.extend(d for d in directories if d not in scope)
+        self.config.filesystem_scope = scope
+
+    def security(
+        self,
+        csp: Optional[str] = None,
+        headers: Optional[Dict[str, Dict[str, str]]] = None,
+        inject_nonce: Optional[bool] = None,
+    ) -> None:
+        """
+        Configure the Content-Security-Policy and response headers of pyframe://.
+
+        Args:
+            csp: Policy for HTML documents, `{nonce}` is replaced with the
+                window's nonce. None keeps the strict default, "" disables it.
+            headers: Glob pattern -> headers, e.g. {"/assets/*": {"Cache-Control": "max-age=3600"}}.
+            inject_nonce: Add the nonce to <script>/<style> tags of bundled HTML (default True).
+        """
+        self.config.security = SecurityOptions(
+            csp=csp,
+            headers=[
+                HeaderRule(pattern=pattern, headers=values)
+                for pattern, values in (headers or {}).items()
+            ]
+            or None,
+            inject_nonce=inject_nonce,
+        )
+
+    def serve_options(
+        self,
+        spa_fallback: Optional[str] = None,
+        cache_control: Optional[str] = None,
+        precompressed: Optional[bool] = None,
+    ) -> None:
+        """
+        Configure how bundled assets are served.
+
+        Args:
+            spa_fallback: Document for unknown non-asset paths, e.g. "index.html",
+                so deep links of client-side routers survive a reload.
+            cache_control: Cache-Control for assets (HTML always gets "no-cache").
+                Responses carry an ETag based on the content hash.
+            precompressed: Serve "<file>.br"/"<file>.gz" from the bundle when
+                available (default True).
+        """
+        self.config.serve = ServeOptions(
+            spa_fallback=spa_fallback,
+            cache_control=cache_control,
+            precompressed=precompressed,
+        )
+
+    def bundle_verification(
+        self,
+        public_key: Optional[str] = None,
+        encryption_key: Optional[str] = None,
+        on_verify_failure: Literal["abort", "warn", "fallback"] = "abort",
+        fallback_dir: Optional[str] = None,
+    ) -> None:
+        """
+        Verify (and decrypt) the resource bundle at startup.
+
+        Args:
+            public_key: Base64 Ed25519 public key, see bundle_public_key().
+                The signature covers the manifest; each file is checked
+                against its SHA-256 digest on first access.
+            encryption_key: Base64 32 byte key of an encrypted bundle.
+            on_verify_failure: "abort" fails startup, "warn" only logs,
+                "fallback" serves fallback_dir instead of the bundle.
+            fallback_dir: Resource directory used with "fallback".
+        """
+        self.config.bundle = BundleOptions(
+            public_key=public_key,
+            encryption_key=encryption_key,
+            on_verify_failure=on_verify_failure,
+            fallback_dir=fallback_dir,
+        )
+
+    def file_operations(
+        self,
+        *scope: str,
+        confirm_recursive_delete: bool = False,
+        audit_log: bool = True,
+    ) -> None:
+        """
+        Restrict the resource and fs APIs that write, move or delete files.
+
+        Args:
+            scope: Directories that may be modified, in the filesystem_scope
+                format (e.g. "$DATA", "$DOCUMENTS/exports"). Defaults to
+                $DATA, $CACHE and $TEMP.
+            confirm_recursive_delete: Show a native confirmation before a folder
+                outside the app's data, cache or temp directory is deleted.
+            audit_log: Append every write, copy and delete to audit.log in the
+                data directory.
+        """
+        self.config.file_operations = FileOperationOptions(
+            scope=list(scope) or None,
+            confirm_recursive_delete=confirm_recursive_delete,
+            audit_log=audit_log,
+        )
+
+    def i18n(
+        self,
+        dir: Optional[str] = None,
+        fallback_locale: Optional[str] = None,
+        locale: Optional[str] = None,
+    ) -> None:
+        """
+        Configure translations. Pages and Python use the i18n handler
+        (i18n.t, i18n.set_locale, ...); menu and tray labels of the form
+        "i18n:<key>" are translated as well and follow locale changes.
+
+        Args:
+            dir: Resource directory with one <locale>.json per locale
+                (default "locales"). Nested objects become dotted keys,
+                objects with plural categories ("one", "few", "other", ...)
+                are plural messages; "{name}" is replaced by arguments.
+            fallback_locale: Last locale of every fallback chain (default "en").
+            locale: Use this locale instead of the system locale.
+        """
+        self.config.i18n = I18nOptions(
+            dir=dir, fallback_locale=fallback_locale, locale=locale
+        )
+
+    def hot_reload(
+        self,
+        enabled: bool = True,
+        debounce_ms: Optional[int] = None,
+        ignore: Optional[List[str]] = None,
+    ) -> None:
+        """
+        Configure hot reload of the debug resource directory. Only active
+        when debug_resource is set.
+
+        Args:
+            enabled: Watch the directory (default True).
+            debounce_ms: Quiet period before changes are applied (default 150).
+            ignore: Glob patterns relative to the directory, replaces the
+                default list (dotfiles, node_modules, __pycache__, backups).
+        """
+        self.config.hot_reload = HotReloadOptions(
+            enabled=enabled, debounce_ms=debounce_ms, ignore=ignore
+        )
+
+    def on_app_event(
+        self,
+        event: Literal[
+            "app.ready",
+            "app.resumed",
+            "app.suspended",
+            "app.allWindowsClosed",
+            "app.beforeQuit",
+            "app.exit",
+            "app.*",
+        ],
+        callback: Callable[[str, Any, Optional[int]], Union[Any, Awaitable[Any]]],
+    ) -> None:
+        """
+        Register `callback(event, data, window_id)` for an app lifecycle event.
+        An "app.beforeQuit" callback returning False cancels the quit; the
+        runtime waits up to two seconds for all callbacks to finish.
+
+        Args:
+            event: Event name or "app.*" for all of them.
+            callback: Sync or async function.
+        """
+        window_events.on(event, callback)
+
+    def on_shutdown(
+        self, callback: Callable[[int], Union[None, Awaitable[None]]]
+    ) -> None:
+        """
+        Register a callback that runs before the app exits. Switches the shutdown
+        hook to "python"; the callback receives the exit code.
+
+        Args:
+            callback: Sync or async function called with the exit code.
+        """
+        if not self._shutdown_callbacks:
+            window_events.on("app.shutdown", self._run_shutdown_callbacks)
+        self._shutdown_callbacks.append(callback)
+        timeout_ms = self.config.shutdown.timeout_ms if self.config.shutdown else None
+        self.config.shutdown = ShutdownOptions(hook="python", timeout_ms=timeout_ms)
+
+    async def _run_shutdown_callbacks(
+        self, event: str, data: Any, window_id: Optional[int]
+    ) -> None:
+        exit_code = (data or {}).get("exitCode", 0)
+        self.fastapi_app.exit_code = exit_code
+        for callback in self._shutdown_callbacks:
+            try:
+                result = callback(exit_code)
+                if asyncio.iscoroutine(result):
+                    await result
+            except Exception as e:
+                print("[Shutdown-Fehler]:", e)
+        await request("app.shutdownComplete", {}, scope=False)
+
     def start_fastapi(self) -> None:
         """
         Start the FastAPI server in a separate thread.
@@ -199,12 +487,25 @@ class PyFrame:
 
     def stop(self) -> None:
         """
-        Stop the webview process and exit the application.
+        Stop the webview process and exit the application with its exit code.
         """
         if self.webview_process and self.webview_process.is_alive():
-            self.webview_process.terminate()
-            self.webview_process.join()
-        sys.exit(0)
+            # Dem Webview Zeit für den eigenen Shutdown geben
+            self.webview_process.join(timeout=5)
+            if self.webview_process.is_alive():
+                self.webview_process.terminate()
+                self.webview_process.join()
+        sys.exit(self.exit_code)
+
+    @property
+    def exit_code(self) -> int:
+        """
+        Exit code of the webview process, or the one passed to /server_shutdown.
+        """
+        if self.webview_process and self.webview_process.exitcode is not None:
+            if self.webview_process.exitcode >= 0:
+                return self.webview_process.exitcode
+        return self.fastapi_app.exit_code or 0
 
     def start(self) -> None:
         """
@@ -227,8 +528,8 @@ class PyFrame:
                     if self.webview_process and self.webview_process.is_alive():
                         self.webview_process.join(timeout=0.5)
                     else:
-                        print("[WARN] webview_process not alive. Waiting...")
-                        time.sleep(0.5)
+                        # Webview hat sich selbst beendet
+                        self.shutdown_event.set()
             except KeyboardInterrupt:
                 print("Keyboard interrupt detected. Exiting...")
                 self.shutdown_event.set()
@@ -255,8 +556,8 @@ class PyFrame:
                 if self.webview_process and self.webview_process.is_alive():
                     self.webview_process.join(timeout=0.5)
                 else:
-                    print("[WARN] webview_process not alive. Waiting...")
-                    time.sleep(0.5)
+                    # Webview hat sich selbst beendet
+                    self.shutdown_event.set()
         except KeyboardInterrupt:
             print("Keyboard interrupt detected. Exiting...")
             self.shutdown_event.set()
@@ -1,5 +1,13 @@
 from .executer import ConnectionsProtocol
 from .menu_executer import MenuAPI
 from .pyinvoker import PyInvoker, command
+from .window_event_executer import WindowEventAPI, window_events
 
-__all__ = ["MenuAPI", "ConnectionsProtocol", "PyInvoker", "command"]
+__all__ = [
+    "MenuAPI",
+    "ConnectionsProtocol",
+    "PyInvoker",
+    "command",
+    "WindowEventAPI",
+    "window_events",
+]
@@ -0,0 +1,139 @@
+import asyncio
+import inspect
+from typing import Any, Callable, Dict, List, Optional, Set, Tuple
+
+from ..executers.executer import ConnectionsProtocol, ProtocolHandlerBase
+from ..runtime import request
+
+WindowEventCallback = Callable[..., Any]
+
+# Only sent to windows that subscribed to them via window.subscribe
+SUBSCRIPTION_EVENTS = (
+    "window.moved",
+    "window.resized",
+    "window.keyboardInput",
+    "window.modifiersChanged",
+    "window.cursorEntered",
+    "window.cursorLeft",
+)
+
+
+def _matches(pattern: str, event: str) -> bool:
+    if pattern in (event, "*"):
+        return True
+    return pattern.endswith(".*") and event.startswith(pattern[:-1])
+
+
+class WindowEventAPI(ProtocolHandlerBase):
+    """
+    Receives window events forwarded by the runtime (protocol "window_event").
+    High-frequency events (window.moved, window.resized, window.keyboardInput,
+    window.modifiersChanged, window.cursorEntered, window.cursorLeft) are only sent
+    after the window subscribed to them via `window.subscribe`; the window
+    handler's on()/off() keep those subscriptions in line with the listeners.
+
+    App lifecycle events (app.ready, app.resumed, app.suspended,
+    app.allWindowsClosed, app.beforeQuit, app.exit) arrive here as well,
+    with window_id None when no window was left to deliver them. An
+    app.beforeQuit callback that returns False cancels the quit; the
+    runtime waits for the answer up to two seconds.
+    """
+
+    def __init__(self):
+        self._listeners: Dict[str, List[Tuple[WindowEventCallback, Optional[int]]]] = {}
+        # Gated events subscribed on behalf of the Python listeners, per window
+        self._subscribed: Dict[int, Set[str]] = {}
+        ConnectionsProtocol.add_protocol("window_event", self)
+
+    def on(
+        self,
+        event: str,
+        callback: WindowEventCallback,
+        window_id: Optional[int] = None,
+    ) -> None:
+        """
+        Register `callback(event, data, window_id)` for `event`.
+        `event` may be an exact name, `window.*` or `*`.
+        """
+        self._listeners.setdefault(event, []).append((callback, window_id))
+
+    def off(self, event: str, callback: Optional[WindowEventCallback] = None) -> None:
+        if callback is None:
+            self._listeners.pop(event, None)
+            return
+        self._listeners[event] = [
+            entry for entry in self._listeners.get(event, []) if entry[0] != callback
+        ]
+
+    def _wanted(self, window_id: int) -> Set[str]:
+        """Subscription-gated events a listener wants from window `window_id`."""
+        return {
+            event
+            for event in SUBSCRIPTION_EVENTS
+            for pattern, entries in self._listeners.items()
+            if _matches(pattern, event)
+            and any(only in (None, window_id) for _, only in entries)
+        }
+
+    async def sync_subscriptions(self, window_ids: Optional[List[int]] = None) -> None:
+        """
+        Subscribe or unsubscribe the gated events of the given windows (default:
+        all open windows) so the runtime sends exactly what the listeners need.
+        Windows opened later are synced when they are shown.
+        """
+        if window_ids is None:
+            window_ids = [window["id"] for window in await request("window.list", {})]
+        for window_id in window_ids:
+            wanted = self._wanted(window_id)
+            current = self._subscribed.get(window_id, set())
+            if wanted - current:
+                payload = {"events": sorted(wanted - current), "id": window_id}
+                await request("window.subscribe", payload, scope=False)
+            if current - wanted:
+                payload = {"events": sorted(current - wanted), "id": window_id}
+                await request("window.unsubscribe", payload, scope=False)
+            self._subscribed[window_id] = wanted
+
+    def subscribed_windows(self) -> List[int]:
+        return list(self._subscribed)
+
+    async def trigger(self, data: Optional[Dict[str, Any]]) -> Any:
+        data = data or {}
+        payload: dict = data.get("payload", data)
+
+        event = payload.get("event")
+        if not event:
+            return
+        window_id = payload.get("windowId")
+        event_data = payload.get("data")
+
+        if (
+            event == "window.shown"
+            and window_id is not None
+            and window_id not in self._subscribed
+        ):
+            asyncio.ensure_future(self.sync_subscriptions([window_id]))
+
+        quit_id = None
+        if event == "app.beforeQuit" and isinstance(event_data, dict):
+            quit_id = event_data.get("quitId")
+        prevent = False
+
+        keys = [event, event.split(".")[0] + ".*", "*"]
+        try:
+            for key in keys:
+                for callback, only_window in list(self._listeners.get(key, [])):
+                    if only_window is not None and only_window != window_id:
+                        continue
+                    result = callback(event, event_data, window_id)
+                    if inspect.isawaitable(result):
+                        result = await result
+                    prevent = prevent or result is False
+        finally:
+            # Der Runtime wartet auf diese Antwort, bevor er beendet
+            if quit_id is not None:
+                payload = {"quit_id": quit_id, "prevent": prevent}
+                await request("app.beforeQuitReply", payload, scope=False)
+
+
+window_events = WindowEventAPI()
@@ -1,4 +1,4 @@
-from typing import List, Optional, Tuple
+from typing import List, Literal, Optional, Tuple
 
 from ..model.models import FrameBackgroundThrottlingPolicy, WindowConfig
 
@@ -99,6 +99,23 @@ class Frame:
         self.__config.window_background_color = rgba
         return self
 
+    def window_show_when(
+        self,
+        when: Literal["immediately", "load", "ready"],
+        timeout_ms: Optional[int] = None,
+    ):
+        self.__config.window_show_when = when
+        self.__config.window_show_timeout_ms = timeout_ms
+        return self
+
+    def window_resize_inset(self, inset: float):
+        self.__config.window_resize_inset = inset
+        return self
+
+    def window_file_drop(self, mode: Literal["page", "native"]):
+        self.__config.window_file_drop = mode
+        return self
+
     def webview_context_id(self, ctx_id: str):
         self.__config.webview_context_id = ctx_id
         return self
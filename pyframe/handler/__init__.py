@@ -1,5 +1,8 @@
 from .dialog import DialogHandel as dialog
 from .extra import ExtraAPI as extra
+from .fs import FsAPI as fs
+from .i18n import I18nAPI as i18n
+from .lifecycle import LifecycleHandel as lifecycle
 from .monitor import MonitorAPI as monitor
 from .notification import control_center_notification as notify
 from .resource import ResourceAPI as resource
@@ -10,6 +13,9 @@ from .window_extra import WindowExtraAPI as window_extra
 __all__ = [
     "dialog",
     "extra",
+    "fs",
+    "i18n",
+    "lifecycle",
     "monitor",
     "notify",
     "resource",
@@ -0,0 +1,129 @@
+from typing import Any, Dict, List, Optional
+
+from ..runtime import request
+
+
+class FsAPI:
+    """
+    Dateisystem-API. Alle Pfade sind relativ zu einem Basisverzeichnis:
+    "appData", "appCache", "temp", "home", "documents" oder "downloads".
+    Absolute Pfade und ".." werden abgelehnt. Lesende Aufrufe müssen im
+    filesystem_scope oder im Schreib-Scope liegen. Schreibende, verschiebende
+    und löschende Aufrufe müssen zusätzlich im Scope von
+    PyFrame.file_operations() liegen und landen im Audit-Log.
+    """
+
+    async def read_file(
+        self, base: str, path: str, encoding: Optional[str] = None
+    ) -> str:
+        payload = {"base": base, "path": path, "encoding": encoding}
+        return await request("fs.readFile", payload, scope=False)
+
+    async def write_file(
+        self, base: str, path: str, contents: str, encoding: Optional[str] = None
+    ) -> None:
+        payload = {
+            "base": base,
+            "path": path,
+            "contents": contents,
+            "encoding": encoding,
+        }
+        return await request("fs.writeFile", payload, scope=False)
+
+    async def append_file(
+        self, base: str, path: str, contents: str, encoding: Optional[str] = None
+    ) -> None:
+        payload = {
+            "base": base,
+            "path": path,
+            "contents": contents,
+            "encoding": encoding,
+        }
+        return await request("fs.appendFile", payload, scope=False)
+
+    async def read_chunk(
+        self, base: str, path: str, offset: int, length: int
+    ) -> Dict[str, Any]:
+        """Liefert {"data": base64, "bytesRead": int, "eof": bool}."""
+        payload = {"base": base, "path": path, "offset": offset, "length": length}
+        return await request("fs.readChunk", payload, scope=False)
+
+    async def write_chunk(
+        self,
+        base: str,
+        path: str,
+        offset: int,
+        data: str,
+        truncate: Optional[bool] = None,
+    ) -> None:
+        payload = {
+            "base": base,
+            "path": path,
+            "offset": offset,
+            "data": data,
+            "truncate": truncate,
+        }
+        return await request("fs.writeChunk", payload, scope=False)
+
+    async def exists(self, base: str, path: str) -> bool:
+        payload = {"base": base, "path": path}
+        return await request("fs.exists", payload, scope=False)
+
+    async def stat(self, base: str, path: str) -> Dict[str, Any]:
+        payload = {"base": base, "path": path}
+        return await request("fs.stat", payload, scope=False)
+
+    async def read_dir(self, base: str, path: str = "") -> List[Dict[str, str]]:
+        payload = {"base": base, "path": path}
+        return await request("fs.readDir", payload, scope=False)
+
+    async def mkdir(
+        self, base: str, path: str, recursive: Optional[bool] = None
+    ) -> None:
+        payload = {"base": base, "path": path, "recursive": recursive}
+        return await request("fs.mkdir", payload, scope=False)
+
+    async def rename(self, base: str, from_path: str, to_path: str) -> None:
+        payload = {"base": base, "from": from_path, "to": to_path}
+        return await request("fs.rename", payload, scope=False)
+
+    async def copy(self, base: str, from_path: str, to_path: str) -> int:
+        payload = {"base": base, "from": from_path, "to": to_path}
+        return await request("fs.copy", payload, scope=False)
+
+    async def remove(
+        self, base: str, path: str, recursive: Optional[bool] = None
+    ) -> None:
+        payload = {"base": base, "path": path, "recursive": recursive}
+        return await request("fs.remove", payload, scope=False)
+
+    async def watch(
+        self,
+        base: str,
+        path: str = "",
+        recursive: Optional[bool] = None,
+        include: Optional[List[str]] = None,
+        exclude: Optional[List[str]] = None,
+        debounce_ms: Optional[int] = None,
+    ) -> int:
+        """
+        Beobachtet eine Datei oder ein Verzeichnis und liefert die Watch-ID.
+        Änderungen kommen gebündelt als Event "fs.changed" mit
+        {"id": int, "changes": [{"kind": "create" | "modify" | "remove" |
+        "rename", "paths": [...]}]} im Fenster und in Python an. Die Pfade
+        sind relativ zu `base`, ebenso die Glob-Muster `include`/`exclude`.
+        Endet mit unwatch() oder wenn das Fenster geschlossen wird.
+        """
+        payload = {
+            "base": base,
+            "path": path,
+            "recursive": recursive,
+            "include": include,
+            "exclude": exclude,
+            "debounce_ms": debounce_ms,
+        }
+        return await request("fs.watch", payload, scope=False)
+
+    async def unwatch(self, id: int) -> None:
+        payload = {"id": id}
+        return await request("fs.unwatch", payload, scope=False)
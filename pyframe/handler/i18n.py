@@ -0,0 +1,33 @@
+from typing import Any, Dict, List, Optional
+
+from ..runtime import request
+
+
+class I18nAPI:
+    """
+    Übersetzungen aus den Katalogen der App (siehe PyFrame.i18n).
+    Ein Sprachwechsel sendet "locale.changed" mit {"locale", "chain"} an
+    alle Fenster und an Python.
+    """
+
+    async def t(
+        self,
+        key: str,
+        args: Optional[Dict[str, Any]] = None,
+        count: Optional[int] = None,
+    ) -> str:
+        payload = {"key": key, "args": args, "count": count}
+        return await request("i18n.t", payload, scope=False)
+
+    async def locale(self) -> Dict[str, Any]:
+        return await request("i18n.locale", {}, scope=False)
+
+    async def set_locale(self, locale: Optional[str] = None) -> str:
+        payload = {"locale": locale}
+        return await request("i18n.setLocale", payload, scope=False)
+
+    async def available(self) -> List[str]:
+        return await request("i18n.available", {}, scope=False)
+
+    async def messages(self) -> Dict[str, Any]:
+        return await request("i18n.messages", {}, scope=False)
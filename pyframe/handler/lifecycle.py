@@ -0,0 +1,25 @@
+from typing import Any, Optional
+
+from ..runtime import request
+
+
+class LifecycleHandel:
+
+    async def quit(self, exit_code: Optional[int] = None) -> Any:
+        return await request("app.quit", {"exit_code": exit_code}, scope=False)
+
+    async def exit(self, exit_code: Optional[int] = None) -> Any:
+        return await request("app.exit", {"exit_code": exit_code}, scope=False)
+
+    async def block_quit(self, blocked: bool) -> Any:
+        return await request("app.blockQuit", {"blocked": blocked}, scope=False)
+
+    async def before_quit_reply(self, quit_id: int, prevent: bool = False) -> Any:
+        payload = {"quit_id": quit_id, "prevent": prevent}
+        return await request("app.beforeQuitReply", payload, scope=False)
+
+    async def shutdown_complete(self) -> Any:
+        return await request("app.shutdownComplete", {}, scope=False)
+
+    async def show_main_window(self) -> Any:
+        return await request("app.showMainWindow", {}, scope=False)
@@ -1,4 +1,4 @@
-from typing import Dict, List, Optional
+from typing import Any, Dict, List, Optional
 
 from ..runtime import request
 
@@ -79,3 +79,31 @@ class ResourceAPI:
     async def hash(self, path: str) -> str:
         payload = {"path": path}
         return await request("resource.hash", payload, scope=False)
+
+    async def files(self, prefix: Optional[str] = None) -> List[str]:
+        payload = {"prefix": prefix}
+        return await request("resource.files", payload, scope=False)
+
+    async def mount(self, id: str, path: str, prefix: Optional[str] = None) -> None:
+        """
+        Mount a directory or .zip archive on top of the resource layers; its
+        files shadow lower layers. With `prefix` (e.g. "plugins/chart") the
+        files are served below pyframe://<app>/<prefix>/. `path` has to lie
+        in the filesystem_scope. Over a signed bundle a prefix is required and
+        the bundle's own files always win over mounted ones.
+        """
+        payload = {"id": id, "path": path, "prefix": prefix}
+        return await request("resource.mount", payload, scope=False)
+
+    async def unmount(self, id: str) -> None:
+        payload = {"id": id}
+        return await request("resource.unmount", payload, scope=False)
+
+    async def info(self, path: str) -> Dict[str, Any]:
+        """Size, compressed size, compression and modification time of a resource."""
+        payload = {"path": path}
+        return await request("resource.info", payload, scope=False)
+
+    async def layers(self) -> List[str]:
+        """Layer ids from the bottom (bundle) to the top."""
+        return await request("resource.layers", {}, scope=False)
@@ -1,5 +1,6 @@
-from typing import Any, Optional
+from typing import Any, Callable, List, Optional
 
+from ..executers.window_event_executer import window_events
 from ..runtime import request
 
 
@@ -244,3 +245,37 @@ class WindowHandel:
         return await request(
             "window.blockCloseRequested", {"blocked": blocked, "id": id}, scope=False
         )
+
+    async def subscribe(self, events: List[str], id: Optional[int] = None) -> Any:
+        return await request(
+            "window.subscribe", {"events": events, "id": id}, scope=False
+        )
+
+    async def unsubscribe(self, events: List[str], id: Optional[int] = None) -> Any:
+        return await request(
+            "window.unsubscribe", {"events": events, "id": id}, scope=False
+        )
+
+    async def subscriptions(self, id: Optional[int] = None) -> Any:
+        return await request("window.subscriptions", {"id": id})
+
+    async def on(
+        self, event: str, callback: Callable[..., Any], id: Optional[int] = None
+    ) -> None:
+        """
+        Register `callback(event, data, window_id)` for window `id` or all
+        windows. Gated events like window.moved or window.keyboardInput are
+        subscribed for the target windows along with the first listener.
+        """
+        window_events.on(event, callback, id)
+        await window_events.sync_subscriptions(None if id is None else [id])
+
+    async def off(
+        self, event: str, callback: Optional[Callable[..., Any]] = None
+    ) -> None:
+        """
+        Remove listeners; gated events nobody listens to anymore are
+        unsubscribed again, including a subscription the page made itself.
+        """
+        window_events.off(event, callback)
+        await window_events.sync_subscriptions(window_events.subscribed_windows())
@@ -364,7 +364,9 @@ class SystemTray(BaseSchema):
     Represents the tray icon configuration, matching the Rust-side options.
     """
 
-    icon: Optional[str] = Field(None, description="Path to the icon file (PNG).")
+    icon: Optional[str] = Field(
+        None, description="Resource path of the icon (PNG, ICO, JPEG, WebP or SVG)."
+    )
     title: Optional[str] = Field(
         None,
         description=(
@@ -484,6 +486,19 @@ class WindowConfig(BaseSchema):
     window_content_protection: Optional[bool] = None
     window_visible_on_all_workspaces: Optional[bool] = None
     window_background_color: Optional[Tuple[int, int, int, int]] = None
+    window_show_when: Optional[Literal["immediately", "load", "ready"]] = Field(
+        default=None,
+        description="Create the window hidden and show it after page load or PyFrame.ready().",
+    )
+    window_show_timeout_ms: Optional[int] = None
+    window_resize_inset: Optional[float] = Field(
+        default=None,
+        description="Resize border of undecorated windows in logical pixels (default 5, 0 disables).",
+    )
+    window_file_drop: Optional[Literal["page", "native"]] = Field(
+        default=None,
+        description="'native' blocks the page's HTML5 drop handlers, window.fileDrop* events are emitted either way.",
+    )
     webview_context_id: Optional[str] = None
     webview_id: Optional[str] = None
     webview_transparent: Optional[bool] = None
@@ -508,6 +523,142 @@ class WindowConfig(BaseSchema):
     webview_initialization_script_for_main_only: Optional[Tuple[str, bool]] = None
 
 
+class NewWindowAction(str, Enum):
+    window = "window"
+    browser = "browser"
+    deny = "deny"
+
+
+class NewWindowRule(BaseSchema):
+    pattern: str = Field(
+        ..., description="Glob pattern matched against the full URL."
+    )
+    action: NewWindowAction
+
+
+class NewWindowOptions(BaseSchema):
+    """
+    Policy for window.open, target=_blank and navigation away from the entry origin.
+    Rules are checked in order, the first match wins.
+    """
+
+    default_action: Optional[NewWindowAction] = None
+    rules: Optional[List[NewWindowRule]] = None
+    window: Optional[WindowConfig] = None
+
+
+class SplashOptions(BaseSchema):
+    """
+    Splash window shown while the Python backend starts.
+    `html` takes precedence over `image`; both are resolved from the resource bundle.
+    """
+
+    image: Optional[str] = None
+    html: Optional[str] = None
+    size: Optional[Tuple[float, float]] = None
+    transparent: Optional[bool] = None
+    min_display_ms: Optional[int] = None
+    handshake_timeout_ms: Optional[int] = None
+
+
+class HeaderRule(BaseSchema):
+    """
+    Extra response headers for pyframe:// paths matching the glob `pattern`
+    (e.g. "/assets/*"). An empty value removes a header.
+    """
+
+    pattern: str
+    headers: Dict[str, str]
+
+
+class BundleOptions(BaseSchema):
+    """
+    Verification of the resource bundle. With `public_key` the bundle must be
+    signed with the matching key (see pack_resources / bundle_public_key).
+    Keys are base64. `on_verify_failure`: "abort" (default) fails startup,
+    "warn" only logs, "fallback" serves `fallback_dir` instead.
+    """
+
+    public_key: Optional[str] = None
+    encryption_key: Optional[str] = None
+    on_verify_failure: Optional[Literal["abort", "warn", "fallback"]] = None
+    fallback_dir: Optional[Path] = None
+
+
+class FileOperationOptions(BaseSchema):
+    """
+    Guards for resource.delete, resource.copy, resource.extract and every fs
+    call that writes, moves or deletes. `scope` uses the filesystem_scope
+    format and defaults to $DATA, $CACHE and $TEMP.
+    `confirm_recursive_delete` asks before deleting a folder outside the app's
+    own directories. `audit_log` (default True) appends every operation to
+    audit.log in the data directory.
+    """
+
+    scope: Optional[List[str]] = None
+    confirm_recursive_delete: Optional[bool] = None
+    audit_log: Optional[bool] = None
+
+
+class I18nOptions(BaseSchema):
+    """
+    Translation catalogs: one `<locale>.json` per locale (e.g. "de-AT.json")
+    in the resource directory `dir` (default "locales"). Lookups fall back
+    from "de-AT" to "de" to `fallback_locale` (default "en"). `locale`
+    replaces the system locale. Menu and tray labels written as
+    "i18n:<key>" are translated from the same catalogs.
+    """
+
+    dir: Optional[str] = None
+    fallback_locale: Optional[str] = None
+    locale: Optional[str] = None
+
+
+class HotReloadOptions(BaseSchema):
+    """
+    Watching of debug_resource. Changed CSS is swapped in place, other changes
+    reload the windows. `ignore` replaces the default glob list.
+    """
+
+    enabled: Optional[bool] = None
+    debounce_ms: Optional[int] = None
+    ignore: Optional[List[str]] = None
+
+
+class SecurityOptions(BaseSchema):
+    """
+    Headers of content served through pyframe://. Without `csp` a strict
+    default policy is sent; "" disables it. `{nonce}` is replaced with the
+    per-window nonce that is also available as `PyFrame.nonce` in JS.
+    """
+
+    csp: Optional[str] = None
+    headers: Optional[List[HeaderRule]] = None
+    inject_nonce: Optional[bool] = None
+
+
+class ServeOptions(BaseSchema):
+    """
+    How bundled assets are served through pyframe://. `spa_fallback` is sent
+    for unknown paths without file extension (client-side routing).
+    """
+
+    spa_fallback: Optional[str] = None
+    cache_control: Optional[str] = None
+    precompressed: Optional[bool] = None
+
+
+class ShutdownOptions(BaseSchema):
+    """
+    What happens with the backend when the app quits.
+    "http" calls /server_shutdown, "python" runs the PyFrame.on_shutdown callbacks.
+    After timeout_ms the app exits in any case.
+    """
+
+    hook: Optional[Literal["http", "python", "none"]] = None
+    timeout_ms: Optional[int] = None
+
+
 class SocketSettings(BaseSchema):
     path: Optional[str] = None
     force_new: Optional[bool] = None
@@ -556,6 +707,7 @@ class AppOptions(BaseSchema):
     menu_mode: Optional[Literal["menu", "tray", "menu_tray"]] = None
     debug_resource: Optional[str] = None
     debug_entry: Optional[str] = None
+    resource_archive: Optional[str] = None
     socket_settings: SocketSettings = Field(default_factory=SocketSettings)
     window: WindowConfig = Field(default_factory=WindowConfig)
     workers: Optional[int] = None
@@ -565,3 +717,15 @@ class AppOptions(BaseSchema):
     tray: Optional[SystemTray] = None
     shortcuts: Optional[FrameShortcutOption] = None
     window_menu: Optional[MenuFrame] = None
+    new_window: Optional[NewWindowOptions] = None
+    splash: Optional[SplashOptions] = None
+    shutdown: Optional[ShutdownOptions] = None
+    close_behavior: Optional[Literal["quit", "hide", "keepRunning"]] = None
+    reopen_shortcut: Optional[str] = None
+    filesystem_scope: Optional[List[str]] = None
+    security: Optional[SecurityOptions] = None
+    serve: Optional[ServeOptions] = None
+    hot_reload: Optional[HotReloadOptions] = None
+    bundle: Optional[BundleOptions] = None
+    file_operations: Optional[FileOperationOptions] = None
+    i18n: Optional[I18nOptions] = None
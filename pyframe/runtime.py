@@ -1,4 +1,5 @@
 import asyncio
+import sys
 import uuid
 from typing import Any, Dict, Optional
 
@@ -15,10 +16,12 @@ def run_webview(config_json: str) -> None:
         config_json (str): JSON string containing the configuration for the webview.
     """
     try:
-
-        create_webview(config_json)
+        exit_code = create_webview(config_json)
     except Exception as e:
         print("[Webview-Fehler]:", e)
+        exit_code = 1
+    # Exit-Code an den Elternprozess (PyFrame.start) weitergeben
+    sys.exit(exit_code)
 
 
 # Global queues for managing state updates and method invocations
@@ -1,7 +1,8 @@
 use pyo3::prelude::*;
 
+/// Runs the webview event loop and returns the exit code of the app.
 #[pyfunction]
-fn create_webview(config_json: String) -> PyResult<()> {
+fn create_webview(config_json: String) -> PyResult<i32> {
     env_logger::init();
     let menu_bar = muda::Menu::new();
     let mut event_loop = runtime::utils::FrameEventLoopBuilder::with_user_event();
@@ -27,10 +28,11 @@ fn create_webview(config_json: String) -> PyResult<()> {
     let app = runtime::CoreApplication::new(&mut event_loop, menu_bar, config_json)
         .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Init failed: {e}")))?;
 
-    app.run(event_loop)
+    let exit_code = app
+        .run(event_loop)
         .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Run failed: {e}")))?;
 
-    Ok(())
+    Ok(exit_code)
 }
 
 #[pyfunction]
@@ -46,6 +48,68 @@ fn create_ico(source_path: &str, target_path: &str) -> PyResult<()> {
     Ok(())
 }
 
+/// Packs a frontend build directory into `RESOURCE_INDEXES` and `RESOURCE_DATA`.
+/// `compression` is one of `store`, `deflate` or `zstd`; by default it is chosen per file.
+/// Keys are base64 encoded 32 byte values.
+#[pyfunction]
+#[pyo3(signature = (
+    source_dir,
+    target_dir,
+    include=None,
+    exclude=None,
+    compression=None,
+    signing_key=None,
+    encryption_key=None,
+    verify=false,
+))]
+fn pack_resources(
+    py: Python<'_>,
+    source_dir: &str,
+    target_dir: &str,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    compression: Option<&str>,
+    signing_key: Option<String>,
+    encryption_key: Option<String>,
+    verify: bool,
+) -> PyResult<PyObject> {
+    use runtime::resource_manager::packer;
+
+    let source_dir = std::path::Path::new(source_dir);
+    let target_dir = std::path::Path::new(target_dir);
+    let compression = compression
+        .map(runtime::resource_manager::bundle::Compression::parse)
+        .transpose()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    let options = packer::PackOptions {
+        include: include.unwrap_or_default(),
+        exclude: exclude.unwrap_or_default(),
+        compression,
+        signing_key,
+        encryption_key,
+    };
+
+    let report = packer::pack(source_dir, target_dir, &options)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Packing failed: {e}")))?;
+    if verify {
+        packer::verify(source_dir, target_dir, &options)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Verification failed: {e}")))?;
+    }
+
+    let result = pyo3::types::PyDict::new(py);
+    result.set_item("files", report.files)?;
+    result.set_item("size", report.size)?;
+    result.set_item("compressed_size", report.compressed_size)?;
+    Ok(result.into_any().unbind())
+}
+
+/// Base64 public key for a base64 Ed25519 signing key, used as `public_key` of the bundle options.
+#[pyfunction]
+fn bundle_public_key(signing_key: &str) -> PyResult<String> {
+    runtime::resource_manager::bundle::public_key_for(signing_key)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
 pub fn get_pyframe_version() -> &'static str {
     // Mapping Cargo versioning (e.g., "1.0-alpha1") to Python's PEP 440 format (e.g., "1.0.0a1")
     // This conversion is a simplified compatibility adjustment and covers most common cases.
@@ -58,7 +122,9 @@ pub fn get_pyframe_version() -> &'static str {
 #[pymodule]
 fn _pyframe(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("__version__", get_pyframe_version())?;
+    m.add_function(wrap_pyfunction!(bundle_public_key, m)?)?;
     m.add_function(wrap_pyfunction!(create_ico, m)?)?;
     m.add_function(wrap_pyfunction!(create_webview, m)?)?;
+    m.add_function(wrap_pyfunction!(pack_resources, m)?)?;
     Ok(())
 }